@@ -0,0 +1,27 @@
+fn main() {
+    #[cfg(feature = "ffi")]
+    generate_header();
+}
+
+/// Generates the C header for the `ffi` module into OUT_DIR. The committed
+/// `include/wordle_solver.h` is only overwritten when `WORDLE_SOLVER_UPDATE_HEADER` is set.
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed=WORDLE_SOLVER_UPDATE_HEADER");
+
+    let config = cbindgen::Config::from_file(format!("{}/cbindgen.toml", crate_dir))
+        .expect("cbindgen.toml is invalid");
+    let bindings = cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("unable to generate the C header");
+    bindings.write_to_file(format!("{}/wordle_solver.h", out_dir));
+    if std::env::var_os("WORDLE_SOLVER_UPDATE_HEADER").is_some() {
+        bindings.write_to_file(format!("{}/include/wordle_solver.h", crate_dir));
+    }
+}
@@ -0,0 +1,97 @@
+#ifndef WORDLE_SOLVER_H
+#define WORDLE_SOLVER_H
+
+/* Generated by cbindgen from src/ffi.rs with `WORDLE_SOLVER_UPDATE_HEADER=1 cargo build --features ffi`. Do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define WORDLE_OK 0
+
+#define WORDLE_INVALID_ARGUMENT 1
+
+#define WORDLE_NO_CANDIDATES 2
+
+/**
+ * Opaque solver handle.
+ */
+typedef struct WordleSolver WordleSolver;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Creates a solver over `answers`, a newline-separated word list, or the built-in answer
+ * list when `answers` is NULL. `alphabet` is a language code (`en`, `es`, `de`, `pl`, `sv`)
+ * or `custom:` followed by the full set of letters, NULL for English. Returns NULL if the
+ * alphabet is unknown or no usable word remains.
+ *
+ * # Safety
+ * `answers` and `alphabet` must be NULL or NUL-terminated strings.
+ */
+struct WordleSolver *wordle_solver_new(const char *answers,
+                                       const char *alphabet,
+                                       size_t word_length,
+                                       size_t max_attempts);
+
+/**
+ * Records the feedback for a played word; `pattern` is one digit per letter
+ * (2 = green, 1 = yellow, 0 = gray).
+ *
+ * # Safety
+ * `solver` must come from `wordle_solver_new`; `guess` and `pattern` must be
+ * NUL-terminated strings.
+ */
+int wordle_solver_push(struct WordleSolver *solver, const char *guess, const char *pattern);
+
+/**
+ * Number of candidate answers consistent with every pushed observation.
+ *
+ * # Safety
+ * `solver` must come from `wordle_solver_new`.
+ */
+size_t wordle_solver_candidate_count(const struct WordleSolver *solver);
+
+/**
+ * Writes the best next guess into `out` (a newly allocated string to release with
+ * `wordle_solver_string_free`) and its entropy into `entropy` when not NULL.
+ *
+ * # Safety
+ * `solver` must come from `wordle_solver_new`; `out` must be a valid pointer and
+ * `entropy` NULL or valid.
+ */
+int wordle_solver_best_guess(struct WordleSolver *solver, char **out, double *entropy);
+
+/**
+ * Message for the last failed call on `solver`, valid until the next call on it.
+ *
+ * # Safety
+ * `solver` must come from `wordle_solver_new`.
+ */
+const char *wordle_solver_last_error(const struct WordleSolver *solver);
+
+/**
+ * Releases a string returned by `wordle_solver_best_guess`.
+ *
+ * # Safety
+ * `text` must be NULL or a string returned by this library, released only once.
+ */
+void wordle_solver_string_free(char *text);
+
+/**
+ * Releases a solver handle.
+ *
+ * # Safety
+ * `solver` must be NULL or come from `wordle_solver_new`, released only once.
+ */
+void wordle_solver_free(struct WordleSolver *solver);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* WORDLE_SOLVER_H */
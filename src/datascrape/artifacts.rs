@@ -0,0 +1,61 @@
+use std::path::{Path, PathBuf};
+
+use crate::session::Session;
+use crate::wordle_game::WordleGame;
+
+/// Directory of page snapshots for one bot run, to diagnose broken selectors afterwards.
+pub struct Artifacts {
+    dir: PathBuf,
+}
+
+impl Artifacts {
+    /// Snapshots go into a subdirectory of `root` named after the current time; it is only
+    /// created on the first capture.
+    pub fn new(root: &Path) -> Self {
+        let started = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
+        Artifacts {
+            dir: root.join(started),
+        }
+    }
+
+    /// Saves `<label>.png`, `<label>.html` and `<label>.json` with the solver state. Failing
+    /// to save is only logged, so it never hides the error being captured.
+    pub async fn capture(
+        &self,
+        client: &fantoccini::Client,
+        label: &str,
+        game: &WordleGame,
+        session: &Session,
+        error: Option<&str>,
+    ) {
+        match self.try_capture(client, label, game, session, error).await {
+            Ok(()) => tracing::info!(dir = %self.dir.display(), label, "saved page snapshot"),
+            Err(e) => tracing::warn!(error = %e, label, "could not save page snapshot"),
+        }
+    }
+
+    async fn try_capture(
+        &self,
+        client: &fantoccini::Client,
+        label: &str,
+        game: &WordleGame,
+        session: &Session,
+        error: Option<&str>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        std::fs::create_dir_all(&self.dir)?;
+        let state = serde_json::json!({
+            "error": error,
+            "url": client.current_url().await.ok().map(|url| url.to_string()),
+            "session": session,
+            "candidates": game.possible_words(),
+        });
+        let state = serde_json::to_string_pretty(&state)?;
+        std::fs::write(self.dir.join(format!("{}.json", label)), state + "\n")?;
+        // the page may be gone, keep what can still be read
+        let html = client.source().await?;
+        std::fs::write(self.dir.join(format!("{}.html", label)), html)?;
+        let png = client.screenshot().await?;
+        std::fs::write(self.dir.join(format!("{}.png", label)), png)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use ahash::AHashMap;
+use fantoccini::Locator;
+
+use super::{RetryPolicy, Site};
+use crate::wordle_game::Observation;
+
+/// How the bot types its guesses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputMethod {
+    /// Key events sent to the page body.
+    #[default]
+    Keys,
+    /// Clicks on the on-screen keyboard, which still works when a dialog holds the focus.
+    Keyboard,
+}
+
+impl InputMethod {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "keys" => Some(InputMethod::Keys),
+            "keyboard" => Some(InputMethod::Keyboard),
+            _ => None,
+        }
+    }
+}
+
+/// Clicks the on-screen button for every character of `keys`, Enter and Backspace included.
+pub async fn click_keys(
+    client: &fantoccini::Client,
+    site: &Site,
+    retry: &RetryPolicy,
+    keys: &str,
+) -> Result<(), fantoccini::error::CmdError> {
+    for c in keys.chars() {
+        let selector = site.key(c);
+        retry
+            .run("click key", || async {
+                client.find(Locator::Css(&selector)).await?.click().await
+            })
+            .await?;
+    }
+    Ok(())
+}
+
+/// The colour every on-screen key shows, as 0 (gray), 1 (yellow) or 2 (green); keys not
+/// played yet are left out.
+pub async fn read_keyboard(
+    client: &fantoccini::Client,
+    site: &Site,
+    retry: &RetryPolicy,
+) -> Result<AHashMap<char, u8>, fantoccini::error::CmdError> {
+    retry
+        .run("read keyboard", || async {
+            let mut states = AHashMap::new();
+            for key in client.find_all(Locator::Css(&site.keys)).await? {
+                let name = key.attr(&site.key_attribute).await?.unwrap_or_default();
+                let state = key.attr(&site.state_attribute).await?.unwrap_or_default();
+                if let (Some(c), Some(feedback)) = (site.key_letter(&name), site.feedback(&state)) {
+                    states.insert(c, feedback);
+                }
+            }
+            Ok(states)
+        })
+        .await
+}
+
+/// Checks a row read from the tiles against the keyboard, which shows the best colour each
+/// letter got in any row: it can never be worse than a tile of that letter.
+pub fn check_keyboard(row: &Observation, keyboard: &AHashMap<char, u8>) -> Result<(), String> {
+    for (c, feedback) in row.guess.chars().zip(row.pattern.chars()) {
+        let feedback = feedback.to_digit(10).unwrap_or(0) as u8;
+        match keyboard.get(&c) {
+            Some(&key) if key >= feedback => {}
+            key => {
+                return Err(format!(
+                    "the tiles of {:?} show {} as {} but the keyboard as {:?}",
+                    row.guess, c, feedback, key
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_check_keyboard() {
+    let row = Observation {
+        guess: "eerie".to_string(),
+        pattern: "21000".to_string(),
+    };
+    let keyboard: AHashMap<char, u8> = [('e', 2), ('r', 0), ('i', 0)].into_iter().collect();
+    assert!(check_keyboard(&row, &keyboard).is_ok());
+
+    let keyboard: AHashMap<char, u8> = [('e', 1), ('r', 0), ('i', 0)].into_iter().collect();
+    assert!(check_keyboard(&row, &keyboard).is_err());
+    assert!(check_keyboard(&row, &AHashMap::new()).is_err());
+}
@@ -1,183 +1,638 @@
 use fantoccini::{ClientBuilder, Locator};
-use std::time::Duration;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use tokio::time::sleep;
+use tracing::Instrument;
 
-use crate::wordle_game;
-use wordle_game::{CharGuess, WordleGame};
+#[cfg(feature = "db")]
+use crate::game_db::{GameDb, GameRecord, Source};
+use crate::session::Session;
+use crate::wordle_game::{
+    AnswerHistory, CharGuess, Observation, ShareGrid, SolverConfig, WordList, WordleGame,
+    puzzle_number,
+};
 
-pub async fn run_wordle_bot() -> Result<(), Box<dyn std::error::Error>> {
-    let mut game = WordleGame::new(6);
-    let mut client = ClientBuilder::native()
-        .connect("http://localhost:50216")
-        .await?;
-
-    client
-        .goto("https://www.nytimes.com/games/wordle/index.html")
-        .await?;
+mod artifacts;
+mod keyboard;
+mod retry;
+mod site;
+mod storage;
+pub use artifacts::Artifacts;
+pub use keyboard::{InputMethod, check_keyboard};
+pub use retry::{RetryPolicy, is_driver_dead, is_transient};
+pub use site::Site;
+pub use storage::{GameStatus, StoredGame};
 
-    sleep(Duration::from_secs(2)).await;
+pub struct BotConfig {
+    pub webdriver_url: String,
+    pub site: Site,
+    /// Words to play with instead of the built-in answers, e.g. for a clone in another
+    /// language or with longer words.
+    pub word_list: Option<WordList>,
+    /// On daily sites, today's answer is appended to `solver.answer_history` once solved.
+    pub solver: SolverConfig,
+    /// Every finished game is appended to this database.
+    #[cfg(feature = "db")]
+    pub game_db: Option<PathBuf>,
+    /// Session file rewritten after every row.
+    pub session: Option<PathBuf>,
+    /// Continue today's game from `session` with its saved configuration, if the file exists.
+    pub resume: bool,
+    /// Cross-check the tiles against the game the page keeps in local storage, and trust
+    /// the stored game when they differ. Only for sites with `local_storage`.
+    pub read_storage: bool,
+    /// On an error, save a screenshot, the page HTML and the solver state into a timestamped
+    /// directory under this one.
+    pub artifacts: Option<PathBuf>,
+    /// Also save them after every row.
+    pub artifacts_every_row: bool,
+    /// Retries for page commands and new sessions when the driver dies.
+    pub retry: RetryPolicy,
+    /// Check every row read from the tiles against the colours of the on-screen keyboard.
+    pub read_keyboard: bool,
+}
 
-    let buttons = client
-        .find_all(Locator::Css(".fides-accept-all-button"))
-        .await?;
-    for b in buttons {
-        if b.is_displayed().await? {
-            b.click().await?;
-            println!("Clicked visible cookie button ✅");
-            break;
+impl Default for BotConfig {
+    fn default() -> Self {
+        BotConfig {
+            webdriver_url: "http://localhost:50216".to_string(),
+            site: Site::nyt(),
+            word_list: None,
+            solver: SolverConfig::default(),
+            #[cfg(feature = "db")]
+            game_db: None,
+            session: None,
+            resume: false,
+            read_storage: false,
+            artifacts: None,
+            artifacts_every_row: false,
+            retry: RetryPolicy::default(),
+            read_keyboard: false,
         }
     }
+}
 
-    sleep(Duration::from_secs(2)).await;
+#[tracing::instrument(
+    name = "game",
+    skip_all,
+    fields(webdriver = %config.webdriver_url, site = %config.site.name)
+)]
+pub async fn run_wordle_bot(config: &BotConfig) -> Result<(), Box<dyn std::error::Error>> {
+    let today = chrono::Local::now().date_naive();
+    let resumed = match &config.session {
+        Some(path) if config.resume && path.exists() => Some(Session::load(path)?),
+        _ => None,
+    };
+    if config.read_storage && !config.site.local_storage {
+        return Err(format!(
+            "{} does not keep its game in local storage",
+            config.site.name
+        )
+        .into());
+    }
+    if let Some(session) = resumed.as_ref().filter(|s| s.date != today.to_string()) {
+        return Err(format!("the session is from {}, not today", session.date).into());
+    }
+    let solver = resumed
+        .as_ref()
+        .map_or_else(|| config.solver.clone(), |s| s.config.clone());
+    let mut game = match &config.word_list {
+        Some(list) => solver.build(list.clone())?,
+        None => {
+            let mut game = WordleGame::new(solver.max_attempts);
+            solver.apply(&mut game)?;
+            game
+        }
+    };
+    let mut session = match resumed {
+        Some(session) if session.finished() => {
+            tracing::info!(
+                rows = session.rows.len(),
+                "the session's game is already over"
+            );
+            return Ok(());
+        }
+        Some(session) => {
+            game = session.restore(&game)?;
+            tracing::info!(rows = session.rows.len(), "resumed session");
+            session
+        }
+        None => Session::new(&solver, &game),
+    };
+    let mut history = match &solver.answer_history {
+        Some(path) => Some(AnswerHistory::load(path, &game.alphabet)?),
+        None => None,
+    };
 
-    let play_buttons = client
-        .find_all(Locator::Css("[data-testid='Play']"))
+    let mut client = ClientBuilder::native()
+        .connect(&config.webdriver_url)
         .await?;
+    tracing::info!(date = %today, "connected");
 
-    println!("Found {} play buttons", play_buttons.len());
+    let artifacts = config.artifacts.as_deref().map(Artifacts::new);
+    let site = &config.site;
+    let url = site.page_url()?;
+    let mut puzzle = puzzle_number(today).filter(|_| site.daily);
+    let mut finished_on_load = None;
+    let mut row_ms = vec![];
+    let mut reconnects = 0;
+    let played = loop {
+        let played: Result<(), Box<dyn std::error::Error>> = async {
+            client.goto(&url).await?;
 
-    for b in play_buttons {
-        if b.is_displayed().await? {
-            b.click().await?;
-            println!("Clicked visible play button ✅");
-            break;
+            for dialog in &site.dialogs {
+                sleep(Duration::from_secs(2)).await;
+                click_first_visible(&client, &config.retry, dialog).await?;
+            }
+
+            // the page is the source of truth: the puzzle may have been started by hand or in
+            // another session on the same browser profile
+            let mut board = read_board(&client, config, game.max_attempts).await?;
+            if let Some(stored) = read_storage(&client, config, puzzle).await? {
+                board = cross_check(board, stored.rows, "board");
+                puzzle = stored.puzzle.or(puzzle);
+                tracing::info!(?puzzle, status = ?stored.status, "stored game");
+            }
+            // a new WebDriver session usually starts from a clean profile with an empty
+            // board: the rows played before the reconnect are typed back in, never dropped
+            if reconnects > 0 && board.len() < session.rows.len() {
+                if !session.rows.starts_with(&board) {
+                    return Err("the page after reconnecting disagrees with the session".into());
+                }
+                tracing::warn!(
+                    saved = session.rows.len(),
+                    on_page = board.len(),
+                    "the new session's page is missing rows, typing them back in"
+                );
+                retype_rows(&client, config, board.len(), &session.rows).await?;
+                board = session.rows.clone();
+            }
+            if board != session.rows {
+                if !session.rows.is_empty() {
+                    tracing::warn!(
+                        saved = session.rows.len(),
+                        on_page = board.len(),
+                        "the page's rows differ from the session, following the page"
+                    );
+                }
+                game = game.replay(&board).map_err(|(index, message)| {
+                    format!("row {} on the page: {}", index + 1, message)
+                })?;
+                tracing::info!(rows = board.len(), "continuing from the rows on the page");
+                session.rows = board;
+                row_ms.clear();
+                if let Some(path) = &config.session {
+                    session.save(path)?;
+                }
+            }
+            // decided on the first load only: after a reconnect the page may show a fresh board
+            if finished_on_load.is_none() {
+                finished_on_load = Some(session.finished());
+                if session.finished() {
+                    tracing::info!("the puzzle was already finished on the page");
+                }
+            }
+
+            while !session.finished() {
+                let row_index = session.rows.len();
+                let span = tracing::info_span!("row", row = row_index + 1);
+                let started = Instant::now();
+                let row = play_row(&mut client, &mut game, row_index, config, puzzle)
+                    .instrument(span)
+                    .await?;
+                // rows restored from the session or the page have no timing
+                row_ms.resize(row_index, None);
+                row_ms.push(Some(started.elapsed().as_millis() as u64));
+                match &config.session {
+                    Some(path) => session.push(row, path)?,
+                    None => session.rows.push(row),
+                }
+                if let Some(artifacts) = artifacts.as_ref().filter(|_| config.artifacts_every_row) {
+                    let label = format!("row-{}", row_index + 1);
+                    artifacts
+                        .capture(&client, &label, &game, &session, None)
+                        .await;
+                }
+            }
+            Ok(())
         }
+        .await;
+        match &played {
+            Err(e) if reconnects < config.retry.reconnects && driver_died(e.as_ref()) => {
+                reconnects += 1;
+                tracing::warn!(
+                    error = %e,
+                    reconnects,
+                    "the WebDriver session died, starting a new one"
+                );
+                match ClientBuilder::native().connect(&config.webdriver_url).await {
+                    Ok(new_client) => {
+                        let old = std::mem::replace(&mut client, new_client);
+                        if let Err(e) = old.close().await {
+                            tracing::debug!(error = %e, "closing the dead session failed");
+                        }
+                    }
+                    Err(e) => break Err(e.into()),
+                }
+            }
+            _ => break played,
+        }
+    };
+    if let (Err(e), Some(artifacts)) = (&played, &artifacts) {
+        artifacts
+            .capture(&client, "error", &game, &session, Some(&e.to_string()))
+            .await;
     }
+    played?;
 
-    sleep(Duration::from_secs(2)).await;
+    let rows = session.rows;
+    let solved = rows.last().is_some_and(is_solved);
+    if let Some(answer) = rows.last().map(|row| &row.guess).filter(|_| solved) {
+        tracing::info!(guesses = rows.len(), %answer, "solved");
+        if let Some(history) = history.as_mut().filter(|_| site.daily) {
+            history.record(today, answer)?;
+        }
+    } else {
+        tracing::warn!(attempts = game.max_attempts, "out of attempts");
+    }
 
-    let close_buttons = client
-        .find_all(Locator::Css("[data-testid='icon-close']"))
-        .await?;
+    // a game that was already over when the page loaded has been recorded by whoever played it
+    #[cfg(feature = "db")]
+    if let Some(path) = config
+        .game_db
+        .as_ref()
+        .filter(|_| finished_on_load != Some(true))
+    {
+        row_ms.resize(rows.len(), None);
+        GameDb::open(path)?.record(&GameRecord {
+            date: today,
+            puzzle,
+            source: Source::Bot,
+            rows: rows.clone(),
+            solved,
+            strategy: game.strategy.name().to_string(),
+            row_ms,
+        })?;
+    }
+
+    let share = ShareGrid::from_rows(&rows, puzzle, game.max_attempts);
+    tracing::info!(share = %share.to_text(false), "share grid");
+    log_review(&game, &rows);
+    Ok(())
+}
 
-    for b in close_buttons {
-        if b.is_displayed().await? {
-            b.click().await?;
-            println!("Clicked close icon ✅");
+fn is_solved(row: &Observation) -> bool {
+    row.pattern.chars().all(|c| c == '2')
+}
+
+/// A tile's letter, lowercased, and the value of the site's state attribute, which only
+/// gives feedback (see [`Site::feedback`]) once the row is submitted.
+struct Tile {
+    letter: String,
+    state: String,
+}
+
+async fn read_tiles(
+    client: &fantoccini::Client,
+    site: &Site,
+    row_index: usize,
+) -> Result<Vec<Tile>, fantoccini::error::CmdError> {
+    let row_selector = site.row_tiles(row_index);
+    let mut tiles = vec![];
+    for tile in client.find_all(Locator::Css(&row_selector)).await? {
+        tiles.push(Tile {
+            letter: tile.text().await?.trim().to_lowercase(),
+            state: tile.attr(&site.state_attribute).await?.unwrap_or_default(),
+        });
+    }
+    tracing::trace!(selector = %row_selector, tiles = tiles.len(), "read tiles");
+    Ok(tiles)
+}
+
+/// Reads the rows already submitted on the page, stopping at the first row with a tile that
+/// is still empty or only typed in.
+async fn read_board(
+    client: &fantoccini::Client,
+    config: &BotConfig,
+    max_attempts: usize,
+) -> Result<Vec<Observation>, fantoccini::error::CmdError> {
+    let mut rows = vec![];
+    for row_index in 0..max_attempts {
+        let tiles = config
+            .retry
+            .run("read row", || read_tiles(client, &config.site, row_index))
+            .await?;
+        let mut guess = String::new();
+        let mut pattern = String::new();
+        for tile in &tiles {
+            let Some(feedback) = config.site.feedback(&tile.state) else {
+                tracing::debug!(rows = rows.len(), "read the board");
+                return Ok(rows);
+            };
+            guess.push_str(&tile.letter);
+            pattern.push(char::from(b'0' + feedback));
+        }
+        if tiles.is_empty() {
             break;
         }
+        tracing::debug!(row = row_index + 1, %guess, %pattern, "row on the page");
+        rows.push(Observation { guess, pattern });
     }
+    tracing::debug!(rows = rows.len(), "read the board");
+    Ok(rows)
+}
 
-    for row_index in 0..game.max_attempts {
-        println!("Starting row {}", row_index);
-
-        sleep(Duration::from_secs(3)).await;
+/// The game saved in local storage when `config.read_storage` is set, unless it is for
+/// another puzzle than `puzzle` or, when `puzzle` is known, has no puzzle number to match.
+async fn read_storage(
+    client: &fantoccini::Client,
+    config: &BotConfig,
+    puzzle: Option<u32>,
+) -> Result<Option<StoredGame>, fantoccini::error::CmdError> {
+    if !config.read_storage {
+        return Ok(None);
+    }
+    let stored = StoredGame::read(client).await?;
+    match stored {
+        // an unnumbered game may be yesterday's or in a format we misread: trust the tiles
+        Some(stored) if puzzle.is_some() && stored.puzzle != puzzle => {
+            tracing::warn!(stored = ?stored.puzzle, expected = ?puzzle, "ignoring the stored game for another puzzle");
+            Ok(None)
+        }
+        stored => Ok(stored),
+    }
+}
 
-        let (guess_word, entropy) = game.entrohpy_allgorithm()?; // propagate error
-        println!(
-            "{:?} is the best guess word with entropy: {}",
-            guess_word, entropy
+/// Prefers the stored rows when they disagree with the ones read from the tiles.
+fn cross_check(tiles: Vec<Observation>, stored: Vec<Observation>, what: &str) -> Vec<Observation> {
+    if tiles != stored {
+        tracing::warn!(
+            ?tiles,
+            ?stored,
+            what,
+            "tiles and local storage disagree, using local storage"
         );
+    }
+    stored
+}
 
-        if let Err(e) = input_word(&mut client, &guess_word).await {
-            println!("Error inputting word: {}", e);
-            break;
+/// Logs the post-game review of every row.
+fn log_review(game: &WordleGame, rows: &[Observation]) {
+    let reviews = match game.review(rows, None) {
+        Ok(reviews) => reviews,
+        Err(e) => {
+            tracing::warn!(error = %e, "could not review the game");
+            return;
         }
+    };
+    for (index, review) in reviews.iter().enumerate() {
+        tracing::info!(
+            row = index + 1,
+            word = %review.guess,
+            pattern = %review.pattern,
+            before = review.candidates_before,
+            after = review.candidates_after,
+            expected_bits = review.expected_bits,
+            actual_bits = review.actual_bits,
+            solver = %review.best.word,
+            solver_after = review.best_after,
+            skill = review.skill,
+            luck = review.luck,
+            "review"
+        );
+    }
+}
 
-        let row_result =
-            match word_results_from_row(&mut client, row_index, guess_word.clone()).await {
-                Ok(res) => res,
-                Err(e) => {
-                    println!("Error getting row results: {}", e);
-                    break;
+/// Clicks the first displayed element matching `selector`, if any.
+async fn click_first_visible(
+    client: &fantoccini::Client,
+    retry: &RetryPolicy,
+    selector: &str,
+) -> Result<bool, fantoccini::error::CmdError> {
+    retry
+        .run("click", || async {
+            let elements = client.find_all(Locator::Css(selector)).await?;
+            let found = elements.len();
+            for element in elements {
+                if element.is_displayed().await? {
+                    element.click().await?;
+                    tracing::debug!(selector, found, "clicked");
+                    return Ok(true);
                 }
-            };
+            }
+            tracing::debug!(selector, found, "nothing visible to click");
+            Ok(false)
+        })
+        .await
+}
 
-        for fb in row_result.iter() {
-            let guess = CharGuess {
-                c: guess_word.chars().nth(fb.position).unwrap() as u8,
-                feedback: fb.feedback,
-                position: fb.position,
-            };
+/// Whether `error` is a WebDriver error after which the session is unusable.
+fn driver_died(error: &(dyn std::error::Error + 'static)) -> bool {
+    error
+        .downcast_ref::<fantoccini::error::CmdError>()
+        .is_some_and(is_driver_dead)
+}
 
-            let is_duplicate = game.correct_gussed_characters.iter().any(|g| {
-                g.c == guess.c && g.feedback == guess.feedback && g.position == guess.position
-            });
+/// Plays one row and feeds its feedback to the solver.
+async fn play_row(
+    client: &mut fantoccini::Client,
+    game: &mut WordleGame,
+    row_index: usize,
+    config: &BotConfig,
+    puzzle: Option<u32>,
+) -> Result<Observation, Box<dyn std::error::Error>> {
+    sleep(Duration::from_secs(3)).await;
 
-            if !is_duplicate {
-                game.correct_gussed_characters.push(guess);
-            }
-        }
+    let analysis = game.entrohpy_allgorithm()?;
+    let best = analysis.best();
+    tracing::info!(
+        word = %best.word,
+        entropy = best.entropy,
+        candidates = analysis.candidates.len(),
+        worst_case = best.worst_case,
+        "guessing"
+    );
+    let guess_word = best.word.clone();
 
-        let display_str: String = row_result
-            .iter()
-            .map(|fb| {
-                let c = guess_word.chars().nth(fb.position).unwrap();
-                match fb.feedback {
-                    2 => c.to_ascii_uppercase(),
-                    1 => c.to_ascii_lowercase(),
-                    _ => '.',
-                }
-            })
-            .collect();
+    submit_word(client, config, row_index, &guess_word).await?;
+    let row_result = word_results_from_row(client, config, row_index, guess_word.clone()).await?;
 
-        println!("Row {} feedback: {}", row_index, display_str);
+    let pattern: String = row_result
+        .iter()
+        .map(|fb| char::from(b'0' + fb.feedback))
+        .collect();
+    let mut row = Observation {
+        guess: guess_word,
+        pattern,
+    };
+    if let Some(stored) = read_storage(client, config, puzzle).await?
+        && let Some(stored_row) = stored.rows.get(row_index)
+    {
+        row = cross_check(vec![row], vec![stored_row.clone()], "row").remove(0);
+    }
+    if config.read_keyboard {
+        let keyboard = keyboard::read_keyboard(client, &config.site, &config.retry).await?;
+        check_keyboard(&row, &keyboard)?;
     }
 
+    game.add_feedback(&row.guess, &row.pattern);
+    tracing::info!(
+        word = %row.guess,
+        pattern = %row.pattern,
+        remaining = game.possible_words().len(),
+        "feedback"
+    );
+
+    Ok(row)
+}
+
+/// Submits the saved `rows` from `from` on and checks the page gives each of them the saved
+/// feedback.
+async fn retype_rows(
+    client: &fantoccini::Client,
+    config: &BotConfig,
+    from: usize,
+    rows: &[Observation],
+) -> Result<(), Box<dyn std::error::Error>> {
+    for (row_index, row) in rows.iter().enumerate().skip(from) {
+        tracing::info!(row = row_index + 1, word = %row.guess, "retyping a saved row");
+        submit_word(client, config, row_index, &row.guess).await?;
+        let pattern: String = word_results_from_row(client, config, row_index, row.guess.clone())
+            .await?
+            .iter()
+            .map(|fb| char::from(b'0' + fb.feedback))
+            .collect();
+        if pattern != row.pattern {
+            return Err(format!(
+                "row {}: the page gives {} for {}, the session saved {}",
+                row_index + 1,
+                pattern,
+                row.guess,
+                row.pattern
+            )
+            .into());
+        }
+    }
     Ok(())
 }
 
-fn input_word(
-    client: &mut fantoccini::Client,
+const ENTER: &str = "\u{E007}";
+const BACKSPACE: &str = "\u{E003}";
+
+/// Types `word` into the row and presses Enter only once the row shows exactly its letters,
+/// so a retried or half-lost key send never submits a wrong word.
+async fn submit_word(
+    client: &fantoccini::Client,
+    config: &BotConfig,
+    row_index: usize,
     word: &str,
-) -> impl std::future::Future<Output = Result<(), fantoccini::error::CmdError>> {
-    let body = client.find(Locator::Css("body"));
-    let word = word.to_string();
-    async move {
-        let body = body.await?;
-        body.send_keys(&word).await?;
-        body.send_keys("\u{E007}").await?; 
-        Ok(())
+) -> Result<(), Box<dyn std::error::Error>> {
+    for _ in 0..config.retry.attempts.max(1) {
+        let typed = typed_letters(client, config, row_index).await?;
+        if typed != word {
+            // clear whatever an earlier try left behind
+            let keys = BACKSPACE.repeat(typed.chars().count()) + word;
+            send_keys(client, config, &keys).await?;
+            sleep(Duration::from_millis(300)).await;
+        }
+        let typed = typed_letters(client, config, row_index).await?;
+        if typed == word {
+            send_keys(client, config, ENTER).await?;
+            return Ok(());
+        }
+        tracing::warn!(%typed, expected = word, "the row does not show the typed word");
     }
+    Err(format!("could not type {:?} into row {}", word, row_index + 1).into())
 }
 
-fn word_results_from_row(
-    client: &mut fantoccini::Client,
+async fn typed_letters(
+    client: &fantoccini::Client,
+    config: &BotConfig,
+    row_index: usize,
+) -> Result<String, fantoccini::error::CmdError> {
+    let tiles = config
+        .retry
+        .run("read row", || read_tiles(client, &config.site, row_index))
+        .await?;
+    Ok(tiles.iter().map(|tile| tile.letter.as_str()).collect())
+}
+
+async fn send_keys(
+    client: &fantoccini::Client,
+    config: &BotConfig,
+    keys: &str,
+) -> Result<(), fantoccini::error::CmdError> {
+    if config.site.input == InputMethod::Keyboard {
+        return keyboard::click_keys(client, &config.site, &config.retry, keys).await;
+    }
+    config
+        .retry
+        .run("send keys", || async {
+            client
+                .find(Locator::Css("body"))
+                .await?
+                .send_keys(keys)
+                .await
+        })
+        .await
+}
+
+async fn word_results_from_row(
+    client: &fantoccini::Client,
+    config: &BotConfig,
     row_index: usize,
     guesed_word: String,
-) -> impl std::future::Future<Output = Result<Vec<CharGuess>, fantoccini::error::CmdError>> + '_ {
-    let row_selector = format!(
-        ".Row-module_row__pwpBq[aria-label='Row {}'] .Tile-module_tile__UWEHN",
-        row_index + 1
-    );
+) -> Result<Vec<CharGuess>, Box<dyn std::error::Error>> {
+    // the tiles flip one after the other
+    sleep(Duration::from_millis(3000) * guesed_word.chars().count() as u32).await;
+    let tiles = config
+        .retry
+        .run("read row", || read_tiles(client, &config.site, row_index))
+        .await?;
+    tracing::debug!(row = row_index + 1, tiles = tiles.len(), "reading row");
+    if tiles.len() != guesed_word.chars().count() {
+        return Err(format!(
+            "found {} tiles for {:?} in row {}",
+            tiles.len(),
+            config.site.row_tiles(row_index),
+            row_index + 1
+        )
+        .into());
+    }
+    let mut char_guesses = Vec::new();
 
-    async move {
-        let tiles = client.find_all(Locator::Css(&row_selector)).await?;
-        let mut char_guesses = Vec::new();
-
-        for (position, tile) in tiles.iter().enumerate() {
-            sleep(Duration::from_millis(3000)).await;
-            let letter = guesed_word
-                .chars()
-                .nth(position)
-                .unwrap_or_default()
-                .to_string();
-            let state = tile.attr("data-state").await?.unwrap_or_default();
-
-            let feedback = match state.as_str() {
-                "correct" => 2, 
-                "present" => 1,
-                "absent" => 0,  
-                _ => 0,         
-            };
-            let c = if let Some(ch) = letter.chars().next() {
-                ch as u8
-            } else {
-                0 
-            };
+    for (position, tile) in tiles.iter().enumerate() {
+        let letter = guesed_word
+            .chars()
+            .nth(position)
+            .unwrap_or_default()
+            .to_string();
+        let state = &tile.state;
+        tracing::trace!(position, %state, "tile");
 
-            char_guesses.push(CharGuess {
-                c,
-                feedback,
-                position,
-            });
-        }
+        let Some(feedback) = config.site.feedback(state) else {
+            return Err(format!(
+                "tile {} of row {} has unexpected state {:?}",
+                position + 1,
+                row_index + 1,
+                state
+            )
+            .into());
+        };
+        let c = letter.chars().next().unwrap_or_default();
 
-        Ok(char_guesses)
+        char_guesses.push(CharGuess {
+            c,
+            feedback,
+            position,
+        });
     }
+
+    Ok(char_guesses)
 }
+
 #[tokio::test]
 async fn test_wordle_bot_runs() {
-    let result = run_wordle_bot().await;
+    let result = run_wordle_bot(&BotConfig::default()).await;
     assert!(result.is_ok(), "Bot failed: {:?}", result);
 }
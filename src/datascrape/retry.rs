@@ -0,0 +1,123 @@
+use fantoccini::error::{CmdError, ErrorStatus};
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// How often and how patiently the bot retries WebDriver commands that failed in a way
+/// that may go away: elements not rendered yet, replaced mid-animation or covered by a
+/// dialog.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Tries per command, the first one included.
+    pub attempts: u32,
+    /// Wait before the first retry; doubled for every further one.
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    /// New WebDriver sessions started after the current one died, per game.
+    pub reconnects: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            attempts: 4,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+            reconnects: 1,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Wait after the `retry`-th failure (0 for the first).
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry);
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+
+    /// Runs `command` until it succeeds, fails with an error that is not transient, or runs
+    /// out of attempts.
+    pub async fn run<T, F, Fut>(&self, what: &str, mut command: F) -> Result<T, CmdError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, CmdError>>,
+    {
+        let mut retry = 0;
+        loop {
+            match command().await {
+                Err(e) if retry + 1 < self.attempts && is_transient(&e) => {
+                    let delay = self.delay(retry);
+                    tracing::debug!(what, error = %e, ?delay, "retrying");
+                    sleep(delay).await;
+                    retry += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Errors a later try of the same command may not hit.
+pub fn is_transient(error: &CmdError) -> bool {
+    match error {
+        CmdError::Standard(e) => matches!(
+            e.error,
+            ErrorStatus::NoSuchElement
+                | ErrorStatus::StaleElementReference
+                | ErrorStatus::ElementNotInteractable
+                | ErrorStatus::ElementClickIntercepted
+                | ErrorStatus::Timeout
+                | ErrorStatus::ScriptTimeout
+        ),
+        CmdError::WaitTimeout => true,
+        _ => false,
+    }
+}
+
+/// Errors after which the WebDriver session cannot be used any more.
+pub fn is_driver_dead(error: &CmdError) -> bool {
+    match error {
+        CmdError::Lost(_) | CmdError::Failed(_) | CmdError::FailedC(_) => true,
+        e => e.is_invalid_session_id() || e.is_no_such_window() || e.is_session_not_created(),
+    }
+}
+
+#[tokio::test]
+async fn test_retry_transient_errors() {
+    use fantoccini::error::WebDriver;
+
+    let policy = RetryPolicy {
+        attempts: 3,
+        initial_delay: Duration::from_millis(1),
+        ..RetryPolicy::default()
+    };
+    assert_eq!(policy.delay(2), Duration::from_millis(4));
+    assert_eq!(policy.delay(40), policy.max_delay);
+
+    let mut tries = 0;
+    let result = policy
+        .run("find", || {
+            tries += 1;
+            let error = WebDriver::new(ErrorStatus::NoSuchElement, "not yet");
+            async move {
+                match tries {
+                    3 => Ok(tries),
+                    _ => Err(CmdError::Standard(error)),
+                }
+            }
+        })
+        .await;
+    assert_eq!(result.unwrap(), 3);
+
+    tries = 0;
+    let result: Result<(), _> = policy
+        .run("find", || {
+            tries += 1;
+            async { Err(CmdError::InvalidArgument("selector".into(), "bad".into())) }
+        })
+        .await;
+    assert!(result.is_err());
+    assert_eq!(tries, 1);
+}
@@ -0,0 +1,255 @@
+//! Site adapters. The NYT selectors are the ones the bot has always played with and the
+//! fixture's are tested against its page. The hello-wordl and react-wordle ones were written
+//! from the projects' sources and have not been verified against the live sites, so
+//! [`Site::from_name`] (and `--site`) leaves them out until they are.
+
+use std::io;
+
+use super::InputMethod;
+
+/// Where a Wordle page lives and how to play it: the bot loop only talks to the page
+/// through these selectors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Site {
+    pub name: String,
+    /// Address of the page, or only the query string passed to `page` when it is set.
+    pub url: String,
+    /// HTML built into the binary, written to a temporary file and opened from there.
+    pub page: Option<&'static str>,
+    /// Buttons clicked in order after loading, when visible: cookie banners, help dialogs.
+    pub dialogs: Vec<String>,
+    pub input: InputMethod,
+    /// CSS selector for the tiles of a row, with `{row}` standing for its 1-based number.
+    pub row_tiles: String,
+    /// CSS selector for every on-screen key; the key's name is in `key_attribute`.
+    pub keys: String,
+    pub key_attribute: String,
+    /// Name of a letter key, with `{key}` standing for the letter.
+    pub key_name: String,
+    /// Names of the on-screen Enter and Backspace keys.
+    pub enter_key: String,
+    pub backspace_key: String,
+    /// Attribute of tiles and keys holding their colour, e.g. `data-state` or `class`.
+    pub state_attribute: String,
+    /// Words in `state_attribute` marking green, yellow and gray.
+    pub correct: String,
+    pub present: String,
+    pub absent: String,
+    /// One puzzle a day, numbered like the NYT's: the answer is added to the answer history
+    /// and the share grid gets the puzzle number.
+    pub daily: bool,
+    /// The page saves its game in local storage in the NYT's format (see [`super::StoredGame`]).
+    pub local_storage: bool,
+}
+
+impl Site {
+    pub fn nyt() -> Self {
+        Site {
+            name: "nyt".to_string(),
+            url: "https://www.nytimes.com/games/wordle/index.html".to_string(),
+            page: None,
+            dialogs: vec![
+                ".fides-accept-all-button".to_string(),
+                "[data-testid='Play']".to_string(),
+                "[data-testid='icon-close']".to_string(),
+            ],
+            input: InputMethod::Keys,
+            row_tiles: ".Row-module_row__pwpBq[aria-label='Row {row}'] .Tile-module_tile__UWEHN"
+                .to_string(),
+            keys: "button[data-key]".to_string(),
+            key_attribute: "data-key".to_string(),
+            key_name: "{key}".to_string(),
+            enter_key: "↵".to_string(),
+            backspace_key: "←".to_string(),
+            state_attribute: "data-state".to_string(),
+            correct: "correct".to_string(),
+            present: "present".to_string(),
+            absent: "absent".to_string(),
+            daily: true,
+            local_storage: true,
+        }
+    }
+
+    /// hello wordl and its forks (e.g. wordlegame.org): any word length, `letter-*` classes.
+    /// Unverified against the live site.
+    pub fn hello_wordl() -> Self {
+        Site {
+            name: "hello-wordl".to_string(),
+            url: "https://hellowordl.net/".to_string(),
+            page: None,
+            dialogs: vec![],
+            input: InputMethod::Keys,
+            row_tiles: ".Game-rows .Row:nth-child({row}) .Row-letter".to_string(),
+            keys: ".Game-keyboard-button[data-key]".to_string(),
+            key_attribute: "data-key".to_string(),
+            key_name: "{key}".to_string(),
+            enter_key: "Enter".to_string(),
+            backspace_key: "Backspace".to_string(),
+            state_attribute: "class".to_string(),
+            correct: "letter-correct".to_string(),
+            present: "letter-elsewhere".to_string(),
+            absent: "letter-absent".to_string(),
+            daily: false,
+            local_storage: false,
+        }
+    }
+
+    /// The open source React Wordle and the many language variants built from it.
+    /// Unverified against the live site.
+    pub fn react_wordle() -> Self {
+        Site {
+            name: "react-wordle".to_string(),
+            url: "https://reactle.vercel.app/".to_string(),
+            page: None,
+            dialogs: vec!["[aria-label='Close']".to_string()],
+            input: InputMethod::Keys,
+            row_tiles: ".grid > div:nth-child({row}) > div".to_string(),
+            keys: "button[aria-label$=' key']".to_string(),
+            key_attribute: "aria-label".to_string(),
+            key_name: "{key} key".to_string(),
+            enter_key: "enter key".to_string(),
+            backspace_key: "delete key".to_string(),
+            state_attribute: "class".to_string(),
+            correct: "correct".to_string(),
+            present: "present".to_string(),
+            absent: "absent".to_string(),
+            daily: false,
+            local_storage: false,
+        }
+    }
+
+    /// The page in `fixtures/wordle.html`, to try the bot without network access. Its answer
+    /// can be set with a `?answer=` url.
+    pub fn fixture() -> Self {
+        Site {
+            name: "fixture".to_string(),
+            url: String::new(),
+            page: Some(include_str!("../../fixtures/wordle.html")),
+            dialogs: vec!["#help .close".to_string()],
+            input: InputMethod::Keys,
+            row_tiles: "#board [data-row='{row}'] .tile".to_string(),
+            keys: "#keyboard button[data-key]".to_string(),
+            key_attribute: "data-key".to_string(),
+            key_name: "{key}".to_string(),
+            enter_key: "Enter".to_string(),
+            backspace_key: "Backspace".to_string(),
+            state_attribute: "data-state".to_string(),
+            correct: "correct".to_string(),
+            present: "present".to_string(),
+            absent: "absent".to_string(),
+            daily: false,
+            local_storage: false,
+        }
+    }
+
+    /// The sites offered by `--site`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "nyt" => Some(Site::nyt()),
+            "fixture" => Some(Site::fixture()),
+            _ => None,
+        }
+    }
+
+    /// The address to open: `url`, or `page` written to a temporary file.
+    pub fn page_url(&self) -> Result<String, io::Error> {
+        let Some(page) = self.page else {
+            return Ok(self.url.clone());
+        };
+        let path = std::env::temp_dir().join(format!("wordle-solver-{}.html", self.name));
+        std::fs::write(&path, page)?;
+        Ok(format!("file://{}{}", path.display(), self.url))
+    }
+
+    pub fn row_tiles(&self, row_index: usize) -> String {
+        self.row_tiles
+            .replace("{row}", &(row_index + 1).to_string())
+    }
+
+    /// Selector of the on-screen key for a typed character, with the WebDriver codes for
+    /// Enter and Backspace mapped to the site's names.
+    pub fn key(&self, c: char) -> String {
+        let name = match c {
+            '\u{E007}' => self.enter_key.clone(),
+            '\u{E003}' => self.backspace_key.clone(),
+            c => self.key_name.replace("{key}", &c.to_string()),
+        };
+        format!("{}[{}='{}']", self.keys, self.key_attribute, name)
+    }
+
+    /// Reads the key's letter back from the value of `key_attribute`.
+    pub fn key_letter(&self, name: &str) -> Option<char> {
+        let (prefix, suffix) = self.key_name.split_once("{key}")?;
+        let mut chars = name.strip_prefix(prefix)?.strip_suffix(suffix)?.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.to_lowercase().next(),
+            _ => None,
+        }
+    }
+
+    /// Feedback digit for a value of `state_attribute`: None while a tile is empty or only
+    /// typed in.
+    pub fn feedback(&self, state: &str) -> Option<u8> {
+        let has = |word: &str| state.split_whitespace().any(|w| w == word);
+        if has(&self.correct) {
+            Some(2)
+        } else if has(&self.present) {
+            Some(1)
+        } else if has(&self.absent) {
+            Some(0)
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn test_site_selectors() {
+    let nyt = Site::nyt();
+    assert_eq!(nyt.feedback("present"), Some(1));
+    assert_eq!(nyt.feedback("tbd"), None);
+    assert_eq!(nyt.key('\u{E007}'), "button[data-key][data-key='↵']");
+
+    let hello = Site::hello_wordl();
+    assert_eq!(
+        hello.row_tiles(2),
+        ".Game-rows .Row:nth-child(3) .Row-letter"
+    );
+    assert_eq!(hello.feedback("Row-letter letter-elsewhere"), Some(1));
+
+    let react = Site::react_wordle();
+    assert_eq!(
+        react.key('q'),
+        "button[aria-label$=' key'][aria-label='q key']"
+    );
+    assert_eq!(react.key_letter("q key"), Some('q'));
+    assert_eq!(react.key_letter("enter key"), None);
+}
+
+#[test]
+fn test_fixture_selectors_match_page() {
+    use scraper::{Html, Selector};
+
+    let site = Site::fixture();
+    let page = Html::parse_document(site.page.unwrap());
+    let count = |selector: &str| page.select(&Selector::parse(selector).unwrap()).count();
+    for row_index in 0..6 {
+        assert_eq!(count(&site.row_tiles(row_index)), 5);
+    }
+    assert_eq!(count(&site.row_tiles(6)), 0);
+    assert_eq!(count(&site.keys), 28);
+    for c in "abcdefghijklmnopqrstuvwxyz\u{E007}\u{E003}".chars() {
+        assert_eq!(count(&site.key(c)), 1, "key {:?}", c);
+    }
+    for dialog in &site.dialogs {
+        assert_eq!(count(dialog), 1);
+    }
+
+    let keys = Selector::parse(&site.keys).unwrap();
+    for key in page.select(&keys) {
+        let name = key.value().attr(&site.key_attribute).unwrap();
+        assert!(
+            site.key_letter(name).is_some() || name == site.enter_key || name == site.backspace_key
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use serde_json::{Map, Value};
+
+use crate::wordle_game::Observation;
+
+/// Collects the values of every local storage key mentioning wordle.
+const READ_STORAGE: &str = "
+    const values = [];
+    for (let i = 0; i < localStorage.length; i++) {
+        const key = localStorage.key(i);
+        if (key.toLowerCase().includes('wordle')) values.push(localStorage.getItem(key));
+    }
+    return values;
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameStatus {
+    InProgress,
+    Won,
+    Lost,
+}
+
+/// The game as the page's own script saved it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredGame {
+    pub puzzle: Option<u32>,
+    pub status: GameStatus,
+    /// Submitted rows, in order.
+    pub rows: Vec<Observation>,
+}
+
+impl StoredGame {
+    /// Reads the saved game from the browser's local storage, if the page has saved one.
+    pub async fn read(
+        client: &fantoccini::Client,
+    ) -> Result<Option<Self>, fantoccini::error::CmdError> {
+        let values = client.execute(READ_STORAGE, vec![]).await?;
+        let game = values
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .filter_map(|text| serde_json::from_str(text).ok())
+            .find_map(|value: Value| StoredGame::from_json(&value));
+        tracing::debug!(found = game.is_some(), "read local storage");
+        Ok(game)
+    }
+
+    /// Finds the first object holding `boardState` and `evaluations`, however deeply the
+    /// app version at hand nests it.
+    pub fn from_json(value: &Value) -> Option<Self> {
+        match value {
+            Value::Object(object) if object.contains_key("boardState") => {
+                StoredGame::from_object(object)
+            }
+            Value::Object(object) => object.values().find_map(StoredGame::from_json),
+            Value::Array(values) => values.iter().find_map(StoredGame::from_json),
+            _ => None,
+        }
+    }
+
+    fn from_object(object: &Map<String, Value>) -> Option<Self> {
+        let words = object.get("boardState")?.as_array()?;
+        let evaluations = object.get("evaluations")?.as_array()?;
+        let mut rows = vec![];
+        for (word, evaluation) in words.iter().zip(evaluations) {
+            let (Some(word), Some(evaluation)) = (word.as_str(), evaluation.as_array()) else {
+                break;
+            };
+            if word.is_empty() {
+                break;
+            }
+            let pattern = evaluation
+                .iter()
+                .map(|state| match state.as_str() {
+                    Some("correct") => Some('2'),
+                    Some("present") => Some('1'),
+                    Some("absent") => Some('0'),
+                    _ => None,
+                })
+                .collect::<Option<String>>()?;
+            rows.push(Observation {
+                guess: word.to_lowercase(),
+                pattern,
+            });
+        }
+
+        let status = ["gameStatus", "status"]
+            .iter()
+            .find_map(|key| object.get(*key)?.as_str())
+            .unwrap_or_default();
+        let status = match status.to_uppercase().as_str() {
+            "WIN" => GameStatus::Won,
+            "FAIL" | "LOSE" => GameStatus::Lost,
+            _ => GameStatus::InProgress,
+        };
+        let puzzle = ["dayOffset", "puzzleId"]
+            .iter()
+            .filter_map(|key| object.get(*key))
+            .find_map(|id| match id {
+                Value::Number(n) => n.as_u64().and_then(|n| u32::try_from(n).ok()),
+                Value::String(s) => s.parse().ok(),
+                _ => None,
+            });
+        Some(StoredGame {
+            puzzle,
+            status,
+            rows,
+        })
+    }
+}
+
+#[test]
+fn test_stored_game_from_json() {
+    let json: Value = serde_json::from_str(
+        r#"{"states": [{"game": {
+            "boardState": ["raise", "mount", "", "", "", ""],
+            "evaluations": [
+                ["absent", "absent", "absent", "absent", "absent"],
+                ["correct", "correct", "correct", "correct", "correct"],
+                null, null, null, null
+            ],
+            "status": "WIN",
+            "dayOffset": 1360
+        }}]}"#,
+    )
+    .unwrap();
+    let game = StoredGame::from_json(&json).unwrap();
+    assert_eq!(game.puzzle, Some(1360));
+    assert_eq!(game.status, GameStatus::Won);
+    assert_eq!(game.rows.len(), 2);
+    assert_eq!(game.rows[1].pattern, "22222");
+}
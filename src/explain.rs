@@ -0,0 +1,121 @@
+use std::io;
+
+use wordle_solver::wordle_game::{GuessExplanation, Observation, PatternBucket, ScoredGuess};
+use wordle_solver::{WordleGame, pattern_from_guess};
+
+/// Prints why `guess` is (or is not) a good move after `rows`: how it splits the candidates,
+/// how it compares with the solver's pick and, when known, what the feedback was worth.
+pub fn run_explain(
+    game: &WordleGame,
+    rows: &[Observation],
+    guess: &str,
+    feedback: Option<&str>,
+    answer: Option<&str>,
+    examples: usize,
+) -> Result<(), io::Error> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidInput, message);
+    let game = game
+        .replay(rows)
+        .map_err(|(index, message)| invalid(format!("row {}: {}", index + 1, message)))?;
+    let explanation = game.explain(guess)?;
+    let answer = answer
+        .map(|word| game.check_word(word))
+        .transpose()
+        .map_err(invalid)?;
+    let guess = &explanation.guess;
+
+    println!(
+        "{} candidates ({:.4} bits of uncertainty)",
+        explanation.candidates, explanation.uncertainty
+    );
+    print_scored("guess", guess, explanation.rank);
+    print_scored("best ", &explanation.best, Some(1));
+    println!(
+        "{} is {:.4} bits short of the best guess",
+        guess.word,
+        explanation.best.entropy - guess.entropy
+    );
+
+    println!("Buckets for {}:", guess.word);
+    for bucket in &explanation.distribution {
+        print_bucket(bucket, examples);
+    }
+
+    if let Some(pattern) = feedback {
+        let observation = game
+            .check_observation(&guess.word, pattern)
+            .map_err(invalid)?;
+        match explanation.feedback(&observation.pattern) {
+            Some(bucket) => println!(
+                "Feedback {}: {} left, {:.4} bits gained (expected {:.4})",
+                bucket.pattern,
+                bucket.words.len(),
+                bucket.information(),
+                guess.entropy
+            ),
+            None => println!(
+                "Feedback {} is impossible for the remaining candidates",
+                observation.pattern
+            ),
+        }
+    }
+
+    if let Some(answer) = answer {
+        print_against(&explanation, &answer);
+    }
+    Ok(())
+}
+
+fn print_scored(label: &str, guess: &ScoredGuess, rank: Option<usize>) {
+    let rank = rank.map_or("unlisted".to_string(), |rank| format!("rank {}", rank));
+    println!(
+        "{} {}: {:.4} bits, {:.1} expected left, worst case {}, {} buckets, {:.2}% answer ({})",
+        label,
+        guess.word,
+        guess.entropy,
+        guess.expected_remaining,
+        guess.worst_case,
+        guess.buckets,
+        guess.probability * 100.0,
+        rank
+    );
+}
+
+fn print_bucket(bucket: &PatternBucket, examples: usize) {
+    let mut words = bucket.words[..bucket.words.len().min(examples)].join(" ");
+    if bucket.words.len() > examples {
+        words.push_str(" ...");
+    }
+    println!(
+        "  {} {:>5} {:>6.2}%  {}",
+        bucket.pattern,
+        bucket.words.len(),
+        bucket.probability * 100.0,
+        words
+    );
+}
+
+/// Compares the feedback the explained guess and the best guess get from the real answer.
+fn print_against(explanation: &GuessExplanation, answer: &str) {
+    let (guess_bucket, best_bucket) = explanation.against(answer);
+    for (guess, bucket) in [
+        (&explanation.guess, guess_bucket),
+        (&explanation.best, best_bucket),
+    ] {
+        let pattern = pattern_from_guess(&guess.word, answer);
+        match bucket {
+            Some(bucket) => println!(
+                "Against {}: {} -> {}, {} left, {:.4} bits gained",
+                answer,
+                guess.word,
+                pattern,
+                bucket.words.len(),
+                bucket.information()
+            ),
+            None => println!(
+                "Against {}: {} -> {}, but {} is not a remaining candidate",
+                answer, guess.word, pattern, answer
+            ),
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! C ABI for embedding the solver. The committed header is `include/wordle_solver.h`;
+//! `WORDLE_SOLVER_UPDATE_HEADER=1 cargo build --features ffi` regenerates it after changes here.
+
+use std::ffi::{CStr, CString, c_char, c_double, c_int};
+use std::ptr;
+
+use crate::wordle_game::{Alphabet, WordList, WordleGame};
+
+/// Opaque solver handle.
+pub struct WordleSolver {
+    game: WordleGame,
+    last_error: CString,
+}
+
+pub const WORDLE_OK: c_int = 0;
+pub const WORDLE_INVALID_ARGUMENT: c_int = 1;
+pub const WORDLE_NO_CANDIDATES: c_int = 2;
+
+impl WordleSolver {
+    fn fail(&mut self, code: c_int, message: &str) -> c_int {
+        self.last_error = CString::new(message.replace('\0', "")).unwrap_or_default();
+        code
+    }
+}
+
+/// Reads a NUL-terminated UTF-8 string, `None` for NULL or invalid UTF-8.
+unsafe fn read_str<'a>(text: *const c_char) -> Option<&'a str> {
+    if text.is_null() {
+        return None;
+    }
+    unsafe { CStr::from_ptr(text) }.to_str().ok()
+}
+
+/// Creates a solver over `answers`, a newline-separated word list, or the built-in answer
+/// list when `answers` is NULL. `alphabet` is a language code (`en`, `es`, `de`, `pl`, `sv`)
+/// or `custom:` followed by the full set of letters, NULL for English. Returns NULL if the
+/// alphabet is unknown or no usable word remains.
+///
+/// # Safety
+/// `answers` and `alphabet` must be NULL or NUL-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wordle_solver_new(
+    answers: *const c_char,
+    alphabet: *const c_char,
+    word_length: usize,
+    max_attempts: usize,
+) -> *mut WordleSolver {
+    let list = match unsafe { read_str(answers) } {
+        None if answers.is_null() => WordList::builtin_answers(),
+        None => return ptr::null_mut(),
+        Some(text) => {
+            let alphabet = unsafe { read_str(alphabet) }.unwrap_or("en");
+            let Ok(alphabet) = Alphabet::parse(alphabet) else {
+                return ptr::null_mut();
+            };
+            let mut list = WordList::new(word_length, alphabet);
+            list.include_str("<ffi>", text);
+            list
+        }
+    };
+    if list.words.is_empty() {
+        return ptr::null_mut();
+    }
+
+    Box::into_raw(Box::new(WordleSolver {
+        game: WordleGame::from_word_list(max_attempts, list),
+        last_error: CString::default(),
+    }))
+}
+
+/// Records the feedback for a played word; `pattern` is one digit per letter
+/// (2 = green, 1 = yellow, 0 = gray).
+///
+/// # Safety
+/// `solver` must come from `wordle_solver_new`; `guess` and `pattern` must be
+/// NUL-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wordle_solver_push(
+    solver: *mut WordleSolver,
+    guess: *const c_char,
+    pattern: *const c_char,
+) -> c_int {
+    let Some(solver) = (unsafe { solver.as_mut() }) else {
+        return WORDLE_INVALID_ARGUMENT;
+    };
+    let (Some(guess), Some(pattern)) = (unsafe { read_str(guess) }, unsafe { read_str(pattern) })
+    else {
+        return solver.fail(
+            WORDLE_INVALID_ARGUMENT,
+            "guess and pattern must be UTF-8 strings",
+        );
+    };
+    match solver.game.check_observation(guess, pattern) {
+        Ok(observation) => {
+            solver
+                .game
+                .add_feedback(&observation.guess, &observation.pattern);
+            WORDLE_OK
+        }
+        Err(message) => solver.fail(WORDLE_INVALID_ARGUMENT, &message),
+    }
+}
+
+/// Number of candidate answers consistent with every pushed observation.
+///
+/// # Safety
+/// `solver` must come from `wordle_solver_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wordle_solver_candidate_count(solver: *const WordleSolver) -> usize {
+    unsafe { solver.as_ref() }.map_or(0, |solver| solver.game.possible_words().len())
+}
+
+/// Writes the best next guess into `out` (a newly allocated string to release with
+/// `wordle_solver_string_free`) and its entropy into `entropy` when not NULL.
+///
+/// # Safety
+/// `solver` must come from `wordle_solver_new`; `out` must be a valid pointer and
+/// `entropy` NULL or valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wordle_solver_best_guess(
+    solver: *mut WordleSolver,
+    out: *mut *mut c_char,
+    entropy: *mut c_double,
+) -> c_int {
+    let Some(solver) = (unsafe { solver.as_mut() }) else {
+        return WORDLE_INVALID_ARGUMENT;
+    };
+    if out.is_null() {
+        return solver.fail(WORDLE_INVALID_ARGUMENT, "out must not be NULL");
+    }
+    if solver.game.possible_words().is_empty() {
+        return solver.fail(
+            WORDLE_NO_CANDIDATES,
+            "no candidate is consistent with the observations",
+        );
+    }
+    let (word, best_entropy) = match solver.game.entrohpy_allgorithm() {
+        Ok(analysis) => (analysis.best().word.clone(), analysis.best().entropy),
+        Err(e) => return solver.fail(WORDLE_INVALID_ARGUMENT, &e.to_string()),
+    };
+    unsafe {
+        *out = CString::new(word).unwrap_or_default().into_raw();
+        if !entropy.is_null() {
+            *entropy = best_entropy;
+        }
+    }
+    WORDLE_OK
+}
+
+/// Message for the last failed call on `solver`, valid until the next call on it.
+///
+/// # Safety
+/// `solver` must come from `wordle_solver_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wordle_solver_last_error(solver: *const WordleSolver) -> *const c_char {
+    unsafe { solver.as_ref() }.map_or(ptr::null(), |solver| solver.last_error.as_ptr())
+}
+
+/// Releases a string returned by `wordle_solver_best_guess`.
+///
+/// # Safety
+/// `text` must be NULL or a string returned by this library, released only once.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wordle_solver_string_free(text: *mut c_char) {
+    if !text.is_null() {
+        drop(unsafe { CString::from_raw(text) });
+    }
+}
+
+/// Releases a solver handle.
+///
+/// # Safety
+/// `solver` must be NULL or come from `wordle_solver_new`, released only once.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wordle_solver_free(solver: *mut WordleSolver) {
+    if !solver.is_null() {
+        drop(unsafe { Box::from_raw(solver) });
+    }
+}
+
+#[test]
+fn test_ffi_round_trip() {
+    let words = CString::new("crane\nslate\nmoist\nbuddy\n").unwrap();
+    unsafe {
+        let solver = wordle_solver_new(words.as_ptr(), ptr::null(), 5, 6);
+        assert!(!solver.is_null());
+        assert_eq!(wordle_solver_candidate_count(solver), 4);
+
+        let (guess, pattern) = (c"crane", c"00000");
+        assert_eq!(
+            wordle_solver_push(solver, guess.as_ptr(), pattern.as_ptr()),
+            WORDLE_OK
+        );
+        assert_eq!(wordle_solver_candidate_count(solver), 2);
+
+        let bad = c"000";
+        assert_eq!(
+            wordle_solver_push(solver, guess.as_ptr(), bad.as_ptr()),
+            WORDLE_INVALID_ARGUMENT
+        );
+        assert!(!CStr::from_ptr(wordle_solver_last_error(solver)).is_empty());
+
+        let mut best = ptr::null_mut();
+        assert_eq!(
+            wordle_solver_best_guess(solver, &mut best, ptr::null_mut()),
+            WORDLE_OK
+        );
+        let word = CStr::from_ptr(best).to_str().unwrap().to_string();
+        assert!(word == "moist" || word == "buddy");
+        wordle_solver_string_free(best);
+        wordle_solver_free(solver);
+    }
+}
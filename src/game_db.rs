@@ -0,0 +1,334 @@
+use ahash::AHashMap;
+use chrono::{Datelike, NaiveDate};
+use rusqlite::{Connection, params};
+use std::path::Path;
+
+use crate::wordle_game::{GameResult, Observation};
+
+/// Where a recorded game was played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// The WebDriver bot on the NYT page.
+    Bot,
+    /// The offline engine against a known answer.
+    Offline,
+    /// Typed in by hand in interactive mode.
+    Manual,
+}
+
+impl Source {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "bot" => Some(Source::Bot),
+            "offline" => Some(Source::Offline),
+            "manual" => Some(Source::Manual),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Source::Bot => "bot",
+            Source::Offline => "offline",
+            Source::Manual => "manual",
+        }
+    }
+}
+
+/// One finished game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameRecord {
+    pub date: NaiveDate,
+    pub puzzle: Option<u32>,
+    pub source: Source,
+    pub rows: Vec<Observation>,
+    pub solved: bool,
+    pub strategy: String,
+    /// Milliseconds spent on each row, from picking the guess to reading the feedback; None
+    /// for rows played before the game was resumed.
+    pub row_ms: Vec<Option<u64>>,
+}
+
+impl GameRecord {
+    pub fn from_result(result: &GameResult, date: NaiveDate, strategy: &str) -> Self {
+        GameRecord {
+            date,
+            puzzle: None,
+            source: Source::Offline,
+            rows: result.observations(),
+            solved: result.solved,
+            strategy: strategy.to_string(),
+            row_ms: vec![],
+        }
+    }
+}
+
+/// SQLite file with one row per played game.
+pub struct GameDb {
+    connection: Connection,
+}
+
+impl GameDb {
+    /// Opens the database, creating the file and table when missing.
+    pub fn open(path: impl AsRef<Path>) -> rusqlite::Result<Self> {
+        let connection = Connection::open(path)?;
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS games (
+                id INTEGER PRIMARY KEY,
+                date TEXT NOT NULL,
+                puzzle INTEGER,
+                source TEXT NOT NULL,
+                guesses TEXT NOT NULL,
+                patterns TEXT NOT NULL,
+                solved INTEGER NOT NULL,
+                strategy TEXT NOT NULL,
+                row_ms TEXT NOT NULL
+            )",
+        )?;
+        Ok(GameDb { connection })
+    }
+
+    pub fn record(&self, game: &GameRecord) -> rusqlite::Result<()> {
+        let join =
+            |f: fn(&Observation) -> &str| game.rows.iter().map(f).collect::<Vec<_>>().join(" ");
+        let row_ms: Vec<String> = game
+            .row_ms
+            .iter()
+            .map(|ms| ms.map_or("-".to_string(), |ms| ms.to_string()))
+            .collect();
+        self.connection.execute(
+            "INSERT INTO games (date, puzzle, source, guesses, patterns, solved, strategy, row_ms)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                game.date.to_string(),
+                game.puzzle,
+                game.source.name(),
+                join(|row| &row.guess),
+                join(|row| &row.pattern),
+                game.solved,
+                game.strategy,
+                row_ms.join(" "),
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Every recorded game, oldest first.
+    pub fn games(&self) -> rusqlite::Result<Vec<GameRecord>> {
+        let mut statement = self.connection.prepare(
+            "SELECT date, puzzle, source, guesses, patterns, solved, strategy, row_ms
+             FROM games ORDER BY date, id",
+        )?;
+        let invalid = |column: usize, message: String| {
+            rusqlite::Error::FromSqlConversionFailure(
+                column,
+                rusqlite::types::Type::Text,
+                message.into(),
+            )
+        };
+        let games = statement.query_map([], |row| {
+            let date: String = row.get(0)?;
+            let source: String = row.get(2)?;
+            let guesses: String = row.get(3)?;
+            let patterns: String = row.get(4)?;
+            let row_ms: String = row.get(7)?;
+            Ok(GameRecord {
+                date: date.parse().map_err(|_| invalid(0, date.clone()))?,
+                puzzle: row.get(1)?,
+                source: Source::from_name(&source).ok_or_else(|| invalid(2, source.clone()))?,
+                rows: guesses
+                    .split_whitespace()
+                    .zip(patterns.split_whitespace())
+                    .map(|(guess, pattern)| Observation {
+                        guess: guess.to_string(),
+                        pattern: pattern.to_string(),
+                    })
+                    .collect(),
+                solved: row.get(5)?,
+                strategy: row.get(6)?,
+                row_ms: row_ms
+                    .split_whitespace()
+                    .map(|ms| ms.parse().ok())
+                    .collect(),
+            })
+        })?;
+        games.collect()
+    }
+}
+
+/// Totals for a set of games, like the NYT statistics screen.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Stats {
+    pub played: usize,
+    pub wins: usize,
+    pub current_streak: usize,
+    pub max_streak: usize,
+    /// Number of wins in 1, 2, ... guesses.
+    pub distribution: Vec<usize>,
+    pub average_guesses: f64,
+}
+
+impl Stats {
+    /// `games` must be in date order. A streak counts the days in a row with a win and ends
+    /// on a loss or a skipped day, so the current streak is 0 unless the last win was today
+    /// or yesterday; offline games are left out of streaks, as they replay old answers in
+    /// bulk. Solved games without rows are skipped.
+    pub fn from_games<'a>(
+        games: impl IntoIterator<Item = &'a GameRecord>,
+        today: NaiveDate,
+    ) -> Self {
+        let mut stats = Stats::default();
+        let mut last_win: Option<NaiveDate> = None;
+        let mut total_guesses = 0;
+        for game in games {
+            if game.solved && game.rows.is_empty() {
+                continue;
+            }
+            stats.played += 1;
+            if game.solved {
+                stats.wins += 1;
+                let guesses = game.rows.len();
+                total_guesses += guesses;
+                if stats.distribution.len() < guesses {
+                    stats.distribution.resize(guesses, 0);
+                }
+                stats.distribution[guesses - 1] += 1;
+            }
+
+            if game.source == Source::Offline {
+                continue;
+            }
+            if !game.solved {
+                stats.current_streak = 0;
+                last_win = None;
+                continue;
+            }
+            stats.current_streak = match last_win {
+                Some(date) if date == game.date => stats.current_streak,
+                Some(date) if (game.date - date).num_days() == 1 => stats.current_streak + 1,
+                _ => 1,
+            };
+            stats.max_streak = stats.max_streak.max(stats.current_streak);
+            last_win = Some(game.date);
+        }
+        if last_win.is_none_or(|date| (today - date).num_days() > 1) {
+            stats.current_streak = 0;
+        }
+        stats.average_guesses = total_guesses as f64 / stats.wins.max(1) as f64;
+        stats
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        self.wins as f64 / self.played.max(1) as f64
+    }
+}
+
+/// Stats per calendar month, oldest first.
+pub fn monthly_stats(games: &[GameRecord], today: NaiveDate) -> Vec<((i32, u32), Stats)> {
+    let mut months: Vec<(i32, u32)> = games
+        .iter()
+        .map(|game| (game.date.year(), game.date.month()))
+        .collect();
+    months.dedup();
+    months
+        .into_iter()
+        .map(|month| {
+            let in_month = games
+                .iter()
+                .filter(|game| (game.date.year(), game.date.month()) == month);
+            (month, Stats::from_games(in_month, today))
+        })
+        .collect()
+}
+
+/// Stats per strategy name, sorted by name.
+pub fn strategy_stats(games: &[GameRecord], today: NaiveDate) -> Vec<(String, Stats)> {
+    let mut by_strategy: AHashMap<&str, Vec<&GameRecord>> = AHashMap::new();
+    for game in games {
+        by_strategy.entry(&game.strategy).or_default().push(game);
+    }
+    let mut stats: Vec<(String, Stats)> = by_strategy
+        .into_iter()
+        .map(|(strategy, games)| (strategy.to_string(), Stats::from_games(games, today)))
+        .collect();
+    stats.sort_by(|a, b| a.0.cmp(&b.0));
+    stats
+}
+
+#[test]
+fn test_record_and_stats() {
+    let db = GameDb::open(":memory:").unwrap();
+    let game = |day: u32, solved: bool, guesses: &[&str]| GameRecord {
+        date: NaiveDate::from_ymd_opt(2025, 3, day).unwrap(),
+        puzzle: Some(1360 + day),
+        source: Source::Bot,
+        rows: guesses
+            .iter()
+            .map(|&guess| Observation {
+                guess: guess.to_string(),
+                pattern: "00000".to_string(),
+            })
+            .collect(),
+        solved,
+        strategy: "entropy".to_string(),
+        row_ms: [None]
+            .into_iter()
+            .chain(vec![Some(120); guesses.len() - 1])
+            .collect(),
+    };
+    for record in [
+        game(1, true, &["raise", "mount"]),
+        game(2, true, &["raise", "clout", "mount"]),
+        game(3, false, &["raise"; 6]),
+        game(4, true, &["raise", "mount"]),
+        game(6, true, &["raise", "mount"]),
+    ] {
+        db.record(&record).unwrap();
+    }
+
+    let games = db.games().unwrap();
+    assert_eq!(games[1], game(2, true, &["raise", "clout", "mount"]));
+    let today = NaiveDate::from_ymd_opt(2025, 3, 7).unwrap();
+    let stats = Stats::from_games(&games, today);
+    assert_eq!((stats.played, stats.wins), (5, 4));
+    assert_eq!((stats.current_streak, stats.max_streak), (1, 2));
+    assert_eq!(stats.distribution, vec![0, 3, 1]);
+    assert_eq!(monthly_stats(&games, today).len(), 1);
+
+    // a missed day ends the current streak
+    let stats = Stats::from_games(&games, today.succ_opt().unwrap());
+    assert_eq!((stats.current_streak, stats.max_streak), (0, 2));
+}
+
+#[test]
+fn test_streaks_count_days() {
+    let game = |day: u32, source: Source, solved: bool| GameRecord {
+        date: NaiveDate::from_ymd_opt(2025, 3, day).unwrap(),
+        puzzle: None,
+        source,
+        rows: vec![
+            Observation {
+                guess: "raise".to_string(),
+                pattern: "22222".to_string(),
+            };
+            solved as usize
+        ],
+        solved,
+        strategy: "entropy".to_string(),
+        row_ms: vec![],
+    };
+    let mut games = vec![game(1, Source::Bot, true), game(1, Source::Manual, true)];
+    games.extend((0..100).map(|_| game(2, Source::Offline, true)));
+    games.push(game(2, Source::Offline, false));
+    games.push(game(3, Source::Bot, true));
+    let today = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+    let stats = Stats::from_games(&games, today);
+    assert_eq!((stats.played, stats.wins), (104, 103));
+    assert_eq!((stats.current_streak, stats.max_streak), (1, 1));
+
+    let empty = GameRecord {
+        rows: vec![],
+        ..game(3, Source::Bot, true)
+    };
+    assert_eq!(Stats::from_games([&empty], today), Stats::default());
+}
@@ -0,0 +1,81 @@
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use wordle_solver::session::Session;
+use wordle_solver::{Observation, WordleGame};
+
+/// Terminal loop for playing along with any Wordle clone: the solver suggests a word and
+/// the user types back the colours as digits (2 = green, 1 = yellow, 0 = gray), optionally
+/// prefixed by the word they actually played. Rows are appended to `session`, which is saved
+/// to `path` after each one; play continues after the rows it already holds.
+pub fn run_interactive(
+    game: &mut WordleGame,
+    session: &mut Session,
+    path: Option<&Path>,
+) -> Result<(), io::Error> {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    for row_index in session.rows.len()..game.max_attempts {
+        let analysis = game.entrohpy_allgorithm()?;
+        let best = analysis.best();
+        println!(
+            "Row {}: try {:?} (entropy {:.4}, {} candidates, at most {} left)",
+            row_index + 1,
+            best.word,
+            best.entropy,
+            analysis.candidates.len(),
+            best.worst_case
+        );
+        let suggestion = best.word.clone();
+
+        let (guess_word, pattern) = loop {
+            print!("feedback> ");
+            io::stdout().flush()?;
+            let Some(line) = lines.next() else {
+                return Ok(());
+            };
+            match parse_feedback_line(game, &line?, &suggestion) {
+                Ok(parsed) => break parsed,
+                Err(e) => println!("{}", e),
+            }
+        };
+
+        game.add_feedback(&guess_word, &pattern);
+        let row = Observation {
+            guess: guess_word.clone(),
+            pattern: pattern.clone(),
+        };
+        match path {
+            Some(path) => session.push(row, path)?,
+            None => session.rows.push(row),
+        }
+        if pattern.chars().all(|c| c == '2') {
+            println!("Solved in {} guesses: {}", row_index + 1, guess_word);
+            return Ok(());
+        }
+    }
+
+    println!("Out of attempts.");
+    Ok(())
+}
+
+fn parse_feedback_line(
+    game: &WordleGame,
+    line: &str,
+    suggestion: &str,
+) -> Result<(String, String), String> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let (word, pattern) = match parts.as_slice() {
+        [pattern] => (suggestion, *pattern),
+        [word, pattern] => (*word, *pattern),
+        _ => {
+            return Err(
+                "Enter the pattern (e.g. 20110), optionally preceded by the word played"
+                    .to_string(),
+            );
+        }
+    };
+    let observation = game.check_observation(word, pattern)?;
+    Ok((observation.guess, observation.pattern))
+}
@@ -0,0 +1,54 @@
+//! Entropy based Wordle solver.
+//!
+//! The solver core lives in [`wordle_game`]: word lists and alphabets, pattern scoring,
+//! the candidate constraints and the guess strategies. The WebDriver bot that plays the
+//! NYT page, and clones through site adapters, is in `datascrape` behind the `bot` feature.
+//!
+//! Cargo features (on by default unless noted):
+//! - `cli`: the `game` binary and its log output
+//! - `bot`: the fantoccini/tokio WebDriver bot
+//! - `tui`: the interactive terminal mode and progress bars
+//! - `db`: SQLite record of played games (`game_db`) and `game stats`
+//! - `server`: the local HTTP JSON API (`game serve`)
+//! - `python`: PyO3 extension module (not default): `maturin develop --release`
+//! - `ffi`: C ABI for the cdylib, header in `include/wordle_solver.h` (not default); rebuild
+//!   with `WORDLE_SOLVER_UPDATE_HEADER=1` to regenerate it
+//! - `session`: JSON session files to resume a game (`session`), used by `bot` and `tui`
+//! - `serde`: Serialize/Deserialize for the public data types
+//! - `wasm`: JS API for `wasm32-unknown-unknown` (not default; rayon is never used there):
+//!   `cargo build --target wasm32-unknown-unknown --no-default-features --features wasm`
+//! - `parallel`: scores guesses on all cores with rayon
+//!
+//! Search details and bot progress are reported through `tracing` spans and events; the
+//! `game` binary prints them to stderr (`-v`, `-q`, `--log-format json`, `RUST_LOG`).
+//!
+//! `cargo build --no-default-features` builds only the solver core, without an async
+//! runtime or HTTP stack.
+
+pub mod wordle_game;
+
+#[cfg(feature = "bot")]
+pub mod datascrape;
+
+#[cfg(feature = "db")]
+pub mod game_db;
+
+#[cfg(feature = "server")]
+pub mod server;
+
+#[cfg(feature = "session")]
+pub mod session;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "python")]
+mod python;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+pub use wordle_game::{
+    Alphabet, CharGuess, GameResult, GuessAnalysis, Observation, PatternBucket, ScoredGuess,
+    SolverConfig, Strategy, WordList, WordleGame, pattern_from_guess,
+};
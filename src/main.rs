@@ -1,17 +1,644 @@
+mod explain;
+#[cfg(feature = "tui")]
+mod interactive;
+mod review;
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use std::io::{self, IsTerminal, Read};
+use std::path::PathBuf;
+#[cfg(feature = "bot")]
+use wordle_solver::datascrape::{BotConfig, InputMethod, RetryPolicy, Site, run_wordle_bot};
+#[cfg(feature = "db")]
+use wordle_solver::game_db::{self, GameDb, GameRecord, Source, Stats};
+#[cfg(feature = "tui")]
+use wordle_solver::session::Session;
+use wordle_solver::wordle_game::{
+    Alphabet, GameResult, Observation, ScoreCurve, ShareGrid, Sigmoid, SolverConfig, Strategy,
+    WordList, simulate,
+};
 
-mod wordle_game;
+#[derive(Parser)]
+#[command(name = "game", about = "Entropy based Wordle solver")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// More log detail (-v: scoring and page actions with timings, -vv: everything)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    /// Only log warnings and errors
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+    #[arg(long, global = true, value_enum, default_value_t = LogFormat::Text)]
+    log_format: LogFormat,
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum LogFormat {
+    Text,
+    /// One JSON object per line, for log ingestion
+    Json,
+}
+
+impl Cli {
+    /// Logs to stderr; `RUST_LOG` overrides the level picked by `-v`/`-q`.
+    fn init_logging(&self) {
+        use tracing_subscriber::EnvFilter;
+        use tracing_subscriber::fmt::format::FmtSpan;
+
+        let level = match (self.quiet, self.verbose) {
+            (true, _) => "warn",
+            (false, 0) => "info",
+            (false, 1) => "debug",
+            (false, _) => "trace",
+        };
+        let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+            EnvFilter::new(format!("warn,wordle_solver={},game={}", level, level))
+        });
+        // closing spans report how long each game, row and guess took
+        let span_events = if self.verbose > 0 {
+            FmtSpan::CLOSE
+        } else {
+            FmtSpan::NONE
+        };
+        let builder = tracing_subscriber::fmt()
+            .with_env_filter(filter)
+            .with_span_events(span_events)
+            .with_ansi(io::stderr().is_terminal())
+            .with_writer(io::stderr);
+        match self.log_format {
+            LogFormat::Text => builder.init(),
+            LogFormat::Json => builder.json().with_current_span(true).init(),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Play Wordle in a WebDriver session, the NYT's unless --site says otherwise (default)
+    #[cfg(feature = "bot")]
+    Bot {
+        /// nyt or fixture (the offline page in fixtures/)
+        #[arg(long, default_value = "nyt", value_parser = parse_site)]
+        site: Box<Site>,
+        /// Address of the site's page, for clones hosted elsewhere; for the fixture, a query
+        /// such as `?answer=plate`
+        #[arg(long)]
+        site_url: Option<String>,
+        /// Word list of the site's language and length (defaults to the built-in answers)
+        #[command(flatten)]
+        word_list: WordListArgs,
+        #[command(flatten)]
+        solver: SolverArgs,
+        #[command(flatten)]
+        session: SessionArgs,
+        /// Cross-check the tiles against the game state the page keeps in local storage (nyt only)
+        #[arg(long)]
+        read_storage: bool,
+        /// On errors, save a screenshot, the page HTML and the solver state under this directory
+        #[arg(long)]
+        artifacts: Option<PathBuf>,
+        /// Also save them after every row
+        #[arg(long, requires = "artifacts")]
+        artifacts_every_row: bool,
+        /// Tries per page command before giving up, with doubling waits in between
+        #[arg(long, default_value_t = RetryPolicy::default().attempts)]
+        retries: u32,
+        /// New WebDriver sessions to start when the current one dies
+        #[arg(long, default_value_t = RetryPolicy::default().reconnects)]
+        reconnects: u32,
+        /// `keys` (key events to the page) or `keyboard` (click the on-screen keyboard);
+        /// defaults to the site's
+        #[arg(long, value_parser = parse_input)]
+        input: Option<InputMethod>,
+        /// Check the tiles of every row against the on-screen keyboard's colours
+        #[arg(long)]
+        read_keyboard: bool,
+        #[cfg(feature = "db")]
+        #[command(flatten)]
+        record: RecordArgs,
+    },
+    /// Suggest guesses and read back the colours from the terminal
+    #[cfg(feature = "tui")]
+    Play {
+        #[command(flatten)]
+        word_list: WordListArgs,
+        #[command(flatten)]
+        solver: SolverArgs,
+        #[command(flatten)]
+        session: SessionArgs,
+        #[cfg(feature = "db")]
+        #[command(flatten)]
+        record: RecordArgs,
+    },
+    /// Serve suggestions as a local HTTP JSON API
+    #[cfg(feature = "server")]
+    Serve {
+        #[command(flatten)]
+        word_list: WordListArgs,
+        #[command(flatten)]
+        solver: SolverArgs,
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        addr: std::net::SocketAddr,
+    },
+    /// Play offline against every answer and report the guess distribution
+    Simulate {
+        #[command(flatten)]
+        word_list: WordListArgs,
+        #[command(flatten)]
+        solver: SolverArgs,
+        /// Only play against the first N answers
+        #[arg(long)]
+        limit: Option<usize>,
+        /// Fit the expected-guesses curve from the simulated games and save it here
+        #[arg(long)]
+        fit_curve: Option<PathBuf>,
+        /// Append the simulated games to this SQLite database, as offline games
+        #[cfg(feature = "db")]
+        #[arg(long)]
+        db: Option<PathBuf>,
+    },
+    /// Win rate, streaks and guess distribution of the recorded games
+    #[cfg(feature = "db")]
+    Stats {
+        #[arg(long, default_value = "games.db")]
+        db: PathBuf,
+        /// Only count games from this source: bot, offline or manual
+        #[arg(long, value_parser = parse_source)]
+        source: Option<Source>,
+    },
+    /// Show how a guess splits the candidates and how it compares with the best guess
+    Explain {
+        #[command(flatten)]
+        word_list: WordListArgs,
+        #[command(flatten)]
+        solver: SolverArgs,
+        guess: String,
+        /// Earlier rows as `word:pattern`, e.g. `raise:01200` (repeatable)
+        #[arg(long = "row", value_parser = parse_row)]
+        rows: Vec<Observation>,
+        /// Feedback the guess got, to report the information actually gained
+        #[arg(long)]
+        feedback: Option<String>,
+        /// The real answer, to compare the guess with the best guess against it
+        #[arg(long)]
+        answer: Option<String>,
+        /// Example words listed per bucket
+        #[arg(long, default_value_t = 5)]
+        examples: usize,
+    },
+    /// Review a finished game row by row: information gained, the solver's pick, skill and luck
+    Review {
+        #[command(flatten)]
+        word_list: WordListArgs,
+        #[command(flatten)]
+        solver: SolverArgs,
+        /// Played rows as `word:pattern` (repeatable)
+        #[arg(long = "row", value_parser = parse_row)]
+        rows: Vec<Observation>,
+        /// The answer, if the game was lost; without rows the solver plays against it
+        #[arg(long)]
+        answer: Option<String>,
+    },
+    /// Read a share grid from stdin: list the guesses that fit each row given the answer,
+    /// or rank the answers by how many ways the grid could have been played
+    Share {
+        #[command(flatten)]
+        word_list: WordListArgs,
+        /// Allowed guesses, one per line (repeatable; defaults to wordle_possibles.txt and the
+        /// answers, or only the answers for another alphabet or length)
+        #[arg(long)]
+        guesses: Vec<PathBuf>,
+        /// The answer the grid was played against
+        #[arg(long)]
+        answer: Option<String>,
+        /// Example words listed per row
+        #[arg(long, default_value_t = 10)]
+        examples: usize,
+        /// Answers listed when ranking
+        #[arg(long, default_value_t = 20)]
+        top: usize,
+    },
+    /// Validate and merge word lists, printing the cleaned list to stdout
+    Words {
+        #[command(flatten)]
+        word_list: WordListArgs,
+    },
+}
+
+#[derive(Args)]
+struct SolverArgs {
+    #[arg(long, default_value_t = 6)]
+    attempts: usize,
+    /// Past answers (`YYYY-MM-DD word` per line) to exclude; the bot appends today's answer
+    #[arg(long)]
+    history: Option<PathBuf>,
+    /// Word commonness scores (`word score` per line) used as answer priors
+    #[arg(long)]
+    frequencies: Option<PathBuf>,
+    /// Position (0-1, least to most common) where the prior weight reaches 0.5
+    #[arg(long, default_value_t = Sigmoid::default().midpoint)]
+    sigmoid_midpoint: f64,
+    #[arg(long, default_value_t = Sigmoid::default().steepness)]
+    sigmoid_steepness: f64,
+    /// `entropy` or `expected-score`
+    #[arg(long, default_value = "entropy", value_parser = parse_strategy)]
+    strategy: Strategy,
+    /// Expected-guesses curve saved by `simulate --fit-curve`
+    #[arg(long)]
+    score_curve: Option<PathBuf>,
+}
+
+fn parse_row(row: &str) -> Result<Observation, String> {
+    match row.split_once([':', '=']) {
+        Some((guess, pattern)) => Ok(Observation {
+            guess: guess.to_string(),
+            pattern: pattern.to_string(),
+        }),
+        None => Err(format!("expected `word:pattern`, got {:?}", row)),
+    }
+}
+
+#[cfg(feature = "db")]
+fn parse_source(name: &str) -> Result<Source, String> {
+    Source::from_name(name).ok_or_else(|| format!("unknown source {:?}", name))
+}
+
+#[cfg(feature = "bot")]
+fn parse_site(name: &str) -> Result<Box<Site>, String> {
+    Site::from_name(name)
+        .map(Box::new)
+        .ok_or_else(|| format!("unknown site {:?}", name))
+}
+
+#[cfg(feature = "bot")]
+fn parse_input(name: &str) -> Result<InputMethod, String> {
+    InputMethod::from_name(name).ok_or_else(|| format!("unknown input method {:?}", name))
+}
+
+fn parse_strategy(name: &str) -> Result<Strategy, String> {
+    Strategy::from_name(name).ok_or_else(|| format!("unknown strategy {:?}", name))
+}
+
+impl SolverArgs {
+    fn config(&self) -> SolverConfig {
+        SolverConfig {
+            max_attempts: self.attempts,
+            answer_history: self.history.clone(),
+            frequencies: self.frequencies.clone(),
+            sigmoid: Sigmoid {
+                midpoint: self.sigmoid_midpoint,
+                steepness: self.sigmoid_steepness,
+            },
+            strategy: self.strategy,
+            score_curve: self.score_curve.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "session")]
+#[derive(Args)]
+struct SessionArgs {
+    /// Save the game to this JSON file after every row
+    #[arg(long)]
+    session: Option<PathBuf>,
+    /// Continue the game saved in --session, with the settings it was started with
+    #[arg(long, requires = "session")]
+    resume: bool,
+}
+
+#[cfg(feature = "session")]
+impl SessionArgs {
+    /// The saved session to continue, if resuming and the file exists.
+    #[cfg(feature = "tui")]
+    fn load(&self) -> Result<Option<Session>, io::Error> {
+        match &self.session {
+            Some(path) if self.resume && path.exists() => Session::load(path).map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(all(feature = "db", any(feature = "bot", feature = "tui")))]
+#[derive(Args)]
+struct RecordArgs {
+    /// SQLite database finished games are appended to (see `game stats`)
+    #[arg(long, default_value = "games.db")]
+    db: PathBuf,
+    /// Don't record the game
+    #[arg(long)]
+    no_record: bool,
+}
+
+#[cfg(all(feature = "db", any(feature = "bot", feature = "tui")))]
+impl RecordArgs {
+    fn path(&self) -> Option<&std::path::Path> {
+        (!self.no_record).then_some(self.db.as_path())
+    }
+}
+
+#[cfg(feature = "db")]
+fn save_games(
+    path: &std::path::Path,
+    games: impl IntoIterator<Item = GameRecord>,
+) -> Result<(), io::Error> {
+    let db = GameDb::open(path).map_err(io::Error::other)?;
+    for game in games {
+        db.record(&game).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
 
-mod  datascrape;
-use datascrape::run_wordle_bot;
+#[derive(Args)]
+struct WordListArgs {
+    /// Word list with one word per line, `-` for stdin (repeatable; defaults to the built-in answers)
+    #[arg(long)]
+    words: Vec<PathBuf>,
+    /// Words to leave out of the list (repeatable)
+    #[arg(long)]
+    exclude: Vec<PathBuf>,
+    /// Alphabet name (en, es, de, pl, sv) or custom:<letters> for a custom one
+    #[arg(long, default_value = "en")]
+    alphabet: String,
+    #[arg(long, default_value_t = 5)]
+    length: usize,
+}
 
+impl WordListArgs {
+    /// Loads the configured lists and reports the summary and any rejected lines on stderr.
+    fn load(&self) -> Result<WordList, io::Error> {
+        let alphabet = Alphabet::parse(&self.alphabet)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let mut list = if self.words.is_empty() {
+            let list = WordList::builtin_answers();
+            if list.alphabet != alphabet || list.word_length != self.length {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "the built-in answers are English five-letter words: pass --words for \
+                     another alphabet or length",
+                ));
+            }
+            list
+        } else {
+            let mut list = WordList::new(self.length, alphabet);
+            for path in &self.words {
+                list.include_path(path)?;
+            }
+            list
+        };
+        for path in &self.exclude {
+            list.exclude_path(path)?;
+        }
+
+        for diagnostic in &list.diagnostics {
+            eprintln!("{}", diagnostic);
+        }
+        eprintln!("{}", list.summary());
+        Ok(list)
+    }
+}
+
+/// Without a subcommand the bot runs with its defaults, when it is compiled in.
+fn default_command() -> Option<Command> {
+    if cfg!(feature = "bot") {
+        Cli::parse_from(["game", "bot"]).command
+    } else {
+        None
+    }
+}
 
 fn main() {
-    let rt = tokio::runtime::Runtime::new().unwrap();
-    rt.block_on(async {
-        if let Err(e) = run_wordle_bot().await {
-            eprintln!("Error running Wordle bot: {}", e);
+    let cli = Cli::parse();
+    cli.init_logging();
+    let Some(command) = cli.command.or_else(default_command) else {
+        let _ = Cli::command().print_help();
+        return;
+    };
+    match command {
+        #[cfg(feature = "bot")]
+        Command::Bot {
+            mut site,
+            site_url,
+            word_list,
+            solver,
+            session,
+            read_storage,
+            artifacts,
+            artifacts_every_row,
+            retries,
+            reconnects,
+            input,
+            read_keyboard,
+            #[cfg(feature = "db")]
+            record,
+        } => {
+            if let Some(url) = site_url {
+                site.url = url;
+            }
+            if let Some(input) = input {
+                site.input = input;
+            }
+            let word_list = match word_list.load() {
+                Ok(list) => list,
+                Err(e) => {
+                    eprintln!("Error loading the word list: {}", e);
+                    return;
+                }
+            };
+            let config = BotConfig {
+                site: *site,
+                word_list: Some(word_list),
+                read_storage,
+                artifacts,
+                artifacts_every_row,
+                retry: RetryPolicy {
+                    attempts: retries,
+                    reconnects,
+                    ..RetryPolicy::default()
+                },
+                read_keyboard,
+                solver: solver.config(),
+                session: session.session,
+                resume: session.resume,
+                #[cfg(feature = "db")]
+                game_db: record.path().map(|path| path.to_path_buf()),
+                ..BotConfig::default()
+            };
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            rt.block_on(async {
+                if let Err(e) = run_wordle_bot(&config).await {
+                    tracing::error!(error = %e, "wordle bot stopped");
+                }
+            });
+        }
+        #[cfg(feature = "tui")]
+        Command::Play {
+            word_list,
+            solver,
+            session: session_args,
+            #[cfg(feature = "db")]
+            record,
+        } => {
+            let result = word_list.load().and_then(|list| {
+                let (mut game, mut session) = match session_args.load()? {
+                    Some(session) => {
+                        let game = session.restore(&session.config.build(list)?)?;
+                        println!("Resuming the game from {}:", session.date);
+                        for row in &session.rows {
+                            println!("  {} {}", row.guess, row.pattern);
+                        }
+                        (game, session)
+                    }
+                    None => {
+                        let config = solver.config();
+                        let game = config.build(list)?;
+                        let session = Session::new(&config, &game);
+                        (game, session)
+                    }
+                };
+                if session.finished() {
+                    println!("The saved game is already over.");
+                    return Ok(());
+                }
+                interactive::run_interactive(
+                    &mut game,
+                    &mut session,
+                    session_args.session.as_deref(),
+                )?;
+                if !session.rows.is_empty() {
+                    let share = ShareGrid::from_rows(&session.rows, None, game.max_attempts);
+                    print!("\n{}", share.to_text(false));
+                }
+                // a game left before the end is kept in the session file, not recorded
+                #[cfg(feature = "db")]
+                let Some(db) = record.path().filter(|_| session.finished()) else {
+                    return Ok(());
+                };
+                #[cfg(feature = "db")]
+                save_games(
+                    db,
+                    [GameRecord {
+                        date: chrono::Local::now().date_naive(),
+                        puzzle: None,
+                        source: Source::Manual,
+                        solved: session
+                            .rows
+                            .last()
+                            .is_some_and(|r| r.pattern.chars().all(|c| c == '2')),
+                        rows: session.rows,
+                        strategy: game.strategy.name().to_string(),
+                        row_ms: vec![],
+                    }],
+                )?;
+                Ok(())
+            });
+            if let Err(e) = result {
+                eprintln!("Error running interactive solver: {}", e);
+            }
         }
-    });
+        #[cfg(feature = "server")]
+        Command::Serve {
+            word_list,
+            solver,
+            addr,
+        } => {
+            let result = word_list.load().and_then(|list| {
+                let game = solver.config().build(list)?;
+                let rt = tokio::runtime::Runtime::new()?;
+                rt.block_on(wordle_solver::server::serve(addr, game))
+            });
+            if let Err(e) = result {
+                tracing::error!(error = %e, "server stopped");
+            }
+        }
+        Command::Simulate {
+            word_list,
+            solver,
+            limit,
+            fit_curve,
+            #[cfg(feature = "db")]
+            db,
+        } => {
+            let result = run_simulation(&word_list, &solver, limit, fit_curve);
+            #[cfg(feature = "db")]
+            let result = result.and_then(|results| {
+                let Some(db) = db else {
+                    return Ok(());
+                };
+                let today = chrono::Local::now().date_naive();
+                save_games(
+                    &db,
+                    results
+                        .iter()
+                        .map(|r| GameRecord::from_result(r, today, solver.strategy.name())),
+                )
+            });
+            if let Err(e) = result {
+                eprintln!("Error running simulation: {}", e);
+            }
+        }
+        #[cfg(feature = "db")]
+        Command::Stats { db, source } => {
+            if let Err(e) = run_stats(&db, source) {
+                eprintln!("Error reading game history: {}", e);
+            }
+        }
+        Command::Explain {
+            word_list,
+            solver,
+            guess,
+            rows,
+            feedback,
+            answer,
+            examples,
+        } => {
+            let result = word_list.load().and_then(|list| {
+                let game = solver.config().build(list)?;
+                explain::run_explain(
+                    &game,
+                    &rows,
+                    &guess,
+                    feedback.as_deref(),
+                    answer.as_deref(),
+                    examples,
+                )
+            });
+            if let Err(e) = result {
+                eprintln!("Error explaining guess: {}", e);
+            }
+        }
+        Command::Review {
+            word_list,
+            solver,
+            rows,
+            answer,
+        } => {
+            let result = word_list.load().and_then(|list| {
+                let mut game = solver.config().build(list)?;
+                review::run_review(&mut game, &rows, answer.as_deref())
+            });
+            if let Err(e) = result {
+                eprintln!("Error reviewing game: {}", e);
+            }
+        }
+        Command::Share {
+            word_list,
+            guesses,
+            answer,
+            examples,
+            top,
+        } => {
+            if let Err(e) = run_share(&word_list, &guesses, answer.as_deref(), examples, top) {
+                eprintln!("Error reading share grid: {}", e);
+            }
+        }
+        Command::Words { word_list } => match word_list.load() {
+            Ok(list) => {
+                for word in &list.words {
+                    println!("{}", word);
+                }
+            }
+            Err(e) => eprintln!("Error loading word lists: {}", e),
+        },
+    }
     // let mut game = wordle_game::WordleGame::new(6);
     // println!(
     //     "Welcome to Wordle! You have {} attempts to guess the 5-letter word.",
@@ -24,3 +651,172 @@ fn main() {
     //     Err(e) => println!("Game ended with error: {}", e),
     // }
 }
+
+fn run_simulation(
+    word_list: &WordListArgs,
+    solver: &SolverArgs,
+    limit: Option<usize>,
+    fit_curve: Option<PathBuf>,
+) -> Result<Vec<GameResult>, io::Error> {
+    let list = word_list.load()?;
+    let mut game = solver.config().build(list)?;
+    let mut answers = game.possible_words();
+    answers.truncate(limit.unwrap_or(answers.len()));
+
+    let results = simulate(&mut game, &answers, true)?;
+    let solved: Vec<usize> = results
+        .iter()
+        .filter(|r| r.solved)
+        .map(|r| r.guesses.len())
+        .collect();
+    println!(
+        "{} games with {}: {} solved, average {:.4} guesses",
+        results.len(),
+        game.strategy.name(),
+        solved.len(),
+        solved.iter().sum::<usize>() as f64 / solved.len().max(1) as f64
+    );
+    for guesses in 1..=game.max_attempts {
+        let count = solved.iter().filter(|&&n| n == guesses).count();
+        println!("  {}: {}", guesses, count);
+    }
+    println!("  X: {}", results.len() - solved.len());
+    for missed in results.iter().filter(|r| !r.solved) {
+        println!("  missed {}: {}", missed.answer, missed.guesses.join(" "));
+    }
+
+    if let Some(path) = fit_curve {
+        let samples: Vec<(f64, f64)> = results
+            .iter()
+            .filter(|r| r.solved)
+            .flat_map(|r| r.samples.iter().copied())
+            .collect();
+        match ScoreCurve::fit(&samples) {
+            Some(curve) => {
+                curve.save(&path)?;
+                println!(
+                    "Fitted curve a = {:.4}, b = {:.4} saved to {}",
+                    curve.a,
+                    curve.b,
+                    path.display()
+                );
+            }
+            None => eprintln!("Not enough samples to fit a curve"),
+        }
+    }
+    Ok(results)
+}
+
+fn run_share(
+    word_list: &WordListArgs,
+    guess_paths: &[PathBuf],
+    answer: Option<&str>,
+    examples: usize,
+    top: usize,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut text = String::new();
+    io::stdin().read_to_string(&mut text)?;
+    let grid = ShareGrid::parse(&text)?;
+    if let Some(puzzle) = grid.puzzle {
+        println!("Puzzle {}", puzzle);
+    }
+
+    let answers = word_list.load()?;
+    let guesses = if guess_paths.is_empty() {
+        // the built-in guesses only fit English five-letter answers
+        let builtin = WordList::builtin_guesses();
+        if builtin.word_length == answers.word_length && builtin.alphabet == answers.alphabet {
+            builtin
+        } else {
+            answers.clone()
+        }
+    } else {
+        let mut guesses = WordList::new(answers.word_length, answers.alphabet.clone());
+        for path in guess_paths {
+            guesses.include_path(path)?;
+        }
+        guesses
+    };
+
+    let Some(answer) = answer else {
+        let ranked = grid.rank_answers(&answers.words, &guesses.words);
+        let total = ranked
+            .iter()
+            .fold(0, |total: u128, (_, grids)| total.saturating_add(*grids));
+        println!("{} answers fit the grid", ranked.len());
+        for (answer, grids) in ranked.iter().take(top) {
+            println!(
+                "  {} {:>12} {:>6.2}%",
+                answer,
+                grids,
+                *grids as f64 / total as f64 * 100.0
+            );
+        }
+        return Ok(());
+    };
+    let answer = answers
+        .alphabet
+        .normalize(answer)
+        .ok_or("the answer uses letters outside the alphabet")?;
+    for (pattern, words) in grid
+        .patterns
+        .iter()
+        .zip(grid.possible_guesses(&answer, &guesses.words))
+    {
+        let mut shown = words[..words.len().min(examples)].join(" ");
+        if words.len() > examples {
+            shown.push_str(" ...");
+        }
+        println!("{} {:>5}  {}", pattern, words.len(), shown);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "db")]
+fn run_stats(
+    path: &std::path::Path,
+    source: Option<Source>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut games = GameDb::open(path)?.games()?;
+    games.retain(|game| source.is_none_or(|source| game.source == source));
+
+    let today = chrono::Local::now().date_naive();
+    let stats = Stats::from_games(&games, today);
+    println!(
+        "Played {}, win rate {:.0}%, current streak {}, max streak {}",
+        stats.played,
+        stats.win_rate() * 100.0,
+        stats.current_streak,
+        stats.max_streak
+    );
+    let widest = stats.distribution.iter().copied().max().unwrap_or(0).max(1);
+    for (index, count) in stats.distribution.iter().enumerate() {
+        println!(
+            "  {}: {:<40} {}",
+            index + 1,
+            "#".repeat(count * 40 / widest),
+            count
+        );
+    }
+
+    println!("By month:");
+    for ((year, month), stats) in game_db::monthly_stats(&games, today) {
+        print_stats_row(&format!("{}-{:02}", year, month), &stats);
+    }
+    println!("By strategy:");
+    for (strategy, stats) in game_db::strategy_stats(&games, today) {
+        print_stats_row(&strategy, &stats);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "db")]
+fn print_stats_row(label: &str, stats: &Stats) {
+    println!(
+        "  {:<15} {:>6} played {:>5.1}% won {:>6.3} guesses",
+        label,
+        stats.played,
+        stats.win_rate() * 100.0,
+        stats.average_guesses
+    );
+}
@@ -0,0 +1,103 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::sync::OnceLock;
+
+use crate::wordle_game::{
+    Observation, Strategy, WordleGame, pattern_from_guess, simulate as simulate_games,
+};
+
+fn base_game() -> &'static WordleGame {
+    static GAME: OnceLock<WordleGame> = OnceLock::new();
+    GAME.get_or_init(|| WordleGame::new(6))
+}
+
+/// Replays `[(guess, pattern), ...]` on the built-in word list.
+fn replay(history: Vec<(String, String)>) -> PyResult<WordleGame> {
+    let history: Vec<Observation> = history
+        .into_iter()
+        .map(|(guess, pattern)| Observation { guess, pattern })
+        .collect();
+    base_game().replay(&history).map_err(|(index, message)| {
+        PyValueError::new_err(format!("history[{}]: {}", index, message))
+    })
+}
+
+/// One simulated game, as returned by `simulate`.
+#[pyclass(name = "GameResult", get_all, frozen)]
+struct PyGameResult {
+    answer: String,
+    guesses: Vec<String>,
+    patterns: Vec<String>,
+    solved: bool,
+}
+
+/// `pattern("crane", "react")` -> `"11201"`
+#[pyfunction]
+fn pattern(guess: &str, answer: &str) -> String {
+    pattern_from_guess(guess, answer)
+}
+
+/// Remaining candidate answers after `[(guess, pattern), ...]`.
+#[pyfunction]
+#[pyo3(signature = (history=vec![]))]
+fn filter(history: Vec<(String, String)>) -> PyResult<Vec<String>> {
+    Ok(replay(history)?.possible_words())
+}
+
+/// Every allowed guess as `(word, entropy, expected_remaining)`, best first. Raises
+/// ValueError when no word fits the history.
+#[pyfunction]
+#[pyo3(signature = (history=vec![]))]
+fn entropy_table(
+    py: Python<'_>,
+    history: Vec<(String, String)>,
+) -> PyResult<Vec<(String, f64, f64)>> {
+    let game = replay(history)?;
+    if game.possible_words().is_empty() {
+        return Err(PyValueError::new_err(
+            "no word in the list fits the history",
+        ));
+    }
+    Ok(py.detach(|| {
+        game.ranked_guesses(usize::MAX)
+            .into_iter()
+            .map(|guess| (guess.word, guess.entropy, guess.expected_remaining))
+            .collect()
+    }))
+}
+
+/// Plays every answer (or the first `limit`) offline with `strategy`
+/// (`"entropy"` or `"expected-score"`).
+#[pyfunction]
+#[pyo3(signature = (strategy="entropy", limit=None))]
+fn simulate(py: Python<'_>, strategy: &str, limit: Option<usize>) -> PyResult<Vec<PyGameResult>> {
+    let strategy = Strategy::from_name(strategy)
+        .ok_or_else(|| PyValueError::new_err(format!("unknown strategy {:?}", strategy)))?;
+    let mut game = base_game().clone();
+    game.strategy = strategy;
+    let mut answers = game.possible_words();
+    answers.truncate(limit.unwrap_or(answers.len()));
+
+    let results = py
+        .detach(|| simulate_games(&mut game, &answers, false))
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Ok(results
+        .into_iter()
+        .map(|r| PyGameResult {
+            answer: r.answer,
+            guesses: r.guesses,
+            patterns: r.patterns,
+            solved: r.solved,
+        })
+        .collect())
+}
+
+#[pymodule]
+fn wordle_solver(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(pattern, m)?)?;
+    m.add_function(wrap_pyfunction!(filter, m)?)?;
+    m.add_function(wrap_pyfunction!(entropy_table, m)?)?;
+    m.add_function(wrap_pyfunction!(simulate, m)?)?;
+    m.add_class::<PyGameResult>()?;
+    Ok(())
+}
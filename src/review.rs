@@ -0,0 +1,81 @@
+use std::io;
+
+use wordle_solver::WordleGame;
+use wordle_solver::wordle_game::{Observation, ShareGrid};
+
+/// Prints the per-row review of a finished game. Without `rows` the solver plays against
+/// `answer` first and reviews its own game.
+pub fn run_review(
+    game: &mut WordleGame,
+    rows: &[Observation],
+    answer: Option<&str>,
+) -> Result<(), io::Error> {
+    let rows = match (rows, answer) {
+        ([], Some(answer)) => {
+            let answer = game
+                .check_word(answer)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+            let result = game.play_against(&answer, None)?;
+            game.reset();
+            result.observations()
+        }
+        ([], None) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "give the played rows with --row, or an --answer for the solver to play",
+            ));
+        }
+        _ => rows.to_vec(),
+    };
+
+    let reviews = game.review(&rows, answer)?;
+    println!(
+        "{:>3}  {:<7} {:<7} {:>6} {:>6} {:>8} {:>8}  {:<16} {:>5} {:>5}",
+        "row",
+        "guess",
+        "pattern",
+        "before",
+        "after",
+        "expected",
+        "actual",
+        "solver",
+        "skill",
+        "luck"
+    );
+    for (index, review) in reviews.iter().enumerate() {
+        let best_after = review
+            .best_after
+            .map_or(String::new(), |n| format!(" -> {}", n));
+        println!(
+            "{:>3}  {:<7} {:<7} {:>6} {:>6} {:>8.2} {:>8.2}  {:<16} {:>5.0} {:>5.0}",
+            index + 1,
+            review.guess,
+            review.pattern,
+            review.candidates_before,
+            review.candidates_after,
+            review.expected_bits,
+            review.actual_bits,
+            format!(
+                "{} {:.2}{}",
+                review.best.word, review.best.entropy, best_after
+            ),
+            review.skill,
+            review.luck
+        );
+    }
+
+    let average = |f: fn(&wordle_solver::wordle_game::RowReview) -> f64| {
+        reviews.iter().map(f).sum::<f64>() / reviews.len().max(1) as f64
+    };
+    println!(
+        "Skill {:.0}, luck {:.0}",
+        average(|r| r.skill),
+        average(|r| r.luck)
+    );
+    println!();
+    print!(
+        "{}",
+        ShareGrid::from_rows(&rows, None, game.max_attempts).to_text(false)
+    );
+    Ok(())
+}
@@ -0,0 +1,310 @@
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full};
+use hyper::body::Incoming;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+
+use crate::wordle_game::{Observation, WordleGame, pattern_from_guess};
+
+#[derive(Deserialize)]
+struct SuggestRequest {
+    #[serde(default)]
+    history: Vec<Observation>,
+    #[serde(default = "default_top")]
+    top: usize,
+}
+
+fn default_top() -> usize {
+    5
+}
+
+#[derive(Deserialize)]
+struct FilterRequest {
+    #[serde(default)]
+    history: Vec<Observation>,
+}
+
+/// A request error, sent back as `{"error": {"code", "message", "field"}}`.
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub code: &'static str,
+    pub message: String,
+    pub field: Option<String>,
+}
+
+impl ApiError {
+    fn bad_request(code: &'static str, message: String, field: Option<String>) -> Self {
+        ApiError {
+            status: StatusCode::BAD_REQUEST,
+            code,
+            message,
+            field,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "error": {
+                "code": self.code,
+                "message": self.message,
+                "field": self.field,
+            }
+        })
+    }
+}
+
+/// Serves the JSON API on `addr` until the process is stopped:
+///
+/// - `POST /suggest` `{"history": [{"guess", "pattern"}], "top": 5}` → best guesses with
+///   entropy, answer probability and expected remaining candidates
+/// - `POST /filter` `{"history": [...]}` → remaining candidates
+/// - `GET /pattern?guess=&answer=` → the feedback pattern
+pub async fn serve(addr: SocketAddr, game: WordleGame) -> Result<(), std::io::Error> {
+    let listener = TcpListener::bind(addr).await?;
+    let game = Arc::new(game);
+    tracing::info!(addr = %listener.local_addr()?, "listening");
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let game = Arc::clone(&game);
+        tokio::spawn(async move {
+            let service = service_fn(move |request| handle(Arc::clone(&game), request));
+            if let Err(e) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .await
+            {
+                tracing::warn!(error = %e, "error serving connection");
+            }
+        });
+    }
+}
+
+async fn handle(
+    game: Arc<WordleGame>,
+    request: Request<Incoming>,
+) -> Result<Response<Full<Bytes>>, Infallible> {
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+    let query = request.uri().query().unwrap_or_default().to_string();
+    let body = match request.into_body().collect().await {
+        Ok(body) => body.to_bytes(),
+        Err(e) => {
+            let error = ApiError::bad_request("invalid_body", e.to_string(), None);
+            return Ok(json_response(error.status, &error.to_json()));
+        }
+    };
+
+    // scoring every guess is CPU bound, keep it off the accept loop
+    let (route_method, route_path) = (method.clone(), path.clone());
+    let result = tokio::task::spawn_blocking(move || {
+        route(&game, &route_method, &route_path, &query, &body)
+    })
+    .await
+    .unwrap_or_else(|e| {
+        Err(ApiError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            code: "internal",
+            message: e.to_string(),
+            field: None,
+        })
+    });
+
+    let response = match result {
+        Ok(value) => json_response(StatusCode::OK, &value),
+        Err(error) => json_response(error.status, &error.to_json()),
+    };
+    tracing::debug!(%method, %path, status = response.status().as_u16(), "request");
+    Ok(response)
+}
+
+fn json_response(status: StatusCode, value: &Value) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::from(value.to_string())));
+    *response.status_mut() = status;
+    response.headers_mut().insert(
+        hyper::header::CONTENT_TYPE,
+        hyper::header::HeaderValue::from_static("application/json"),
+    );
+    response
+}
+
+/// Dispatches one request against a template game; the template itself is never mutated.
+pub fn route(
+    game: &WordleGame,
+    method: &Method,
+    path: &str,
+    query: &str,
+    body: &[u8],
+) -> Result<Value, ApiError> {
+    let expected = match path {
+        "/suggest" | "/filter" => Method::POST,
+        "/pattern" => Method::GET,
+        _ => {
+            return Err(ApiError {
+                status: StatusCode::NOT_FOUND,
+                code: "not_found",
+                message: format!("no endpoint at {}", path),
+                field: None,
+            });
+        }
+    };
+    if *method != expected {
+        return Err(ApiError {
+            status: StatusCode::METHOD_NOT_ALLOWED,
+            code: "method_not_allowed",
+            message: format!("{} expects {}", path, expected),
+            field: None,
+        });
+    }
+
+    match path {
+        "/suggest" => {
+            let request: SuggestRequest = parse_body(body)?;
+            let game = replay(game, &request.history)?;
+            if game.possible_words().is_empty() {
+                return Err(ApiError::bad_request(
+                    "no_candidates",
+                    "no word in the list fits the history".to_string(),
+                    Some("history".to_string()),
+                ));
+            }
+            let suggestions = game.ranked_guesses(request.top);
+            Ok(json!({
+                "remaining": game.possible_words().len(),
+                "suggestions": suggestions,
+            }))
+        }
+        "/filter" => {
+            let request: FilterRequest = parse_body(body)?;
+            let candidates = replay(game, &request.history)?.possible_words();
+            Ok(json!({
+                "count": candidates.len(),
+                "candidates": candidates,
+            }))
+        }
+        _ => {
+            let params = parse_query(query);
+            let word = |name: &str| {
+                let value = params
+                    .iter()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value.as_str())
+                    .unwrap_or_default();
+                game.check_observation(value, &"0".repeat(game.word_length))
+                    .map(|observation| observation.guess)
+                    .map_err(|message| {
+                        ApiError::bad_request("invalid_word", message, Some(name.to_string()))
+                    })
+            };
+            let (guess, answer) = (word("guess")?, word("answer")?);
+            Ok(json!({
+                "guess": guess,
+                "answer": answer,
+                "pattern": pattern_from_guess(&guess, &answer),
+            }))
+        }
+    }
+}
+
+fn parse_body<T: for<'de> Deserialize<'de>>(body: &[u8]) -> Result<T, ApiError> {
+    let body = if body.is_empty() {
+        b"{}".as_slice()
+    } else {
+        body
+    };
+    serde_json::from_slice(body)
+        .map_err(|e| ApiError::bad_request("invalid_json", e.to_string(), None))
+}
+
+/// Fresh copy of `game` with the validated history applied.
+fn replay(game: &WordleGame, history: &[Observation]) -> Result<WordleGame, ApiError> {
+    game.replay(history).map_err(|(index, message)| {
+        ApiError::bad_request(
+            "invalid_observation",
+            message,
+            Some(format!("history[{}]", index)),
+        )
+    })
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(key), percent_decode(value))
+        })
+        .collect()
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], escaped) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (b'+', _) => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[test]
+fn test_route_validates_requests() {
+    use crate::wordle_game::{Alphabet, WordList};
+
+    let mut list = WordList::new(5, Alphabet::english());
+    list.include_str("test", "crane\nreact\nslate\nmoist\nbuddy\n");
+    let game = WordleGame::from_word_list(6, list);
+
+    let pattern = route(
+        &game,
+        &Method::GET,
+        "/pattern",
+        "guess=CRANE&answer=react",
+        b"",
+    )
+    .unwrap();
+    assert_eq!(pattern["pattern"], "11201");
+
+    let body = br#"{"history": [{"guess": "crane", "pattern": "00000"}]}"#;
+    let filtered = route(&game, &Method::POST, "/filter", "", body).unwrap();
+    assert_eq!(filtered["candidates"], json!(["moist", "buddy"]));
+
+    let body = br#"{"history": [{"guess": "slate", "pattern": "0001"}]}"#;
+    let error = route(&game, &Method::POST, "/suggest", "", body).unwrap_err();
+    assert_eq!(error.code, "invalid_observation");
+    assert_eq!(error.field.as_deref(), Some("history[0]"));
+
+    let body = br#"{"history": [{"guess": "slate", "pattern": "22222"},
+                                {"guess": "crane", "pattern": "22222"}]}"#;
+    let error = route(&game, &Method::POST, "/suggest", "", body).unwrap_err();
+    assert_eq!(
+        (error.status, error.code),
+        (StatusCode::BAD_REQUEST, "no_candidates")
+    );
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::Path;
+
+use crate::wordle_game::{Observation, SolverConfig, WordleGame};
+
+/// Size and FNV-1a hash of a word list, to check a resumed session runs on the same words.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListHash {
+    pub words: usize,
+    pub fnv1a: String,
+}
+
+impl ListHash {
+    pub fn of(words: &[String]) -> Self {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        for byte in words.iter().flat_map(|w| w.bytes().chain([b'\n'])) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        ListHash {
+            words: words.len(),
+            fnv1a: format!("{:016x}", hash),
+        }
+    }
+}
+
+/// Everything needed to pick a game back up: written after every row.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    /// Day the game was started on, `YYYY-MM-DD`.
+    pub date: String,
+    pub config: SolverConfig,
+    /// Allowed guesses.
+    pub guesses: ListHash,
+    /// Candidate answers before the first row, after the answer history was applied.
+    pub answers: ListHash,
+    pub rows: Vec<Observation>,
+}
+
+impl Session {
+    /// A session for a game that has not played any row yet.
+    pub fn new(config: &SolverConfig, game: &WordleGame) -> Self {
+        let mut fresh = game.clone();
+        fresh.reset();
+        Session {
+            date: chrono::Local::now().date_naive().to_string(),
+            config: config.clone(),
+            guesses: ListHash::of(fresh.words()),
+            answers: ListHash::of(&fresh.possible_words()),
+            rows: vec![],
+        }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, io::Error> {
+        let contents = std::fs::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Writes the session next to `path` and renames it over, so a crash mid-write keeps
+    /// the previous version.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), io::Error> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        let partial = path.with_extension("partial");
+        std::fs::write(&partial, json + "\n")?;
+        std::fs::rename(partial, path)
+    }
+
+    /// Appends a played row and saves.
+    pub fn push(&mut self, row: Observation, path: impl AsRef<Path>) -> Result<(), io::Error> {
+        self.rows.push(row);
+        self.save(path)
+    }
+
+    /// Replays the rows on a copy of `game`, built with the saved configuration. Fails when
+    /// its words differ from the ones the session was started with.
+    pub fn restore(&self, game: &WordleGame) -> Result<WordleGame, io::Error> {
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+        let started = Session::new(&self.config, game);
+        if started.guesses != self.guesses || started.answers != self.answers {
+            return Err(invalid(
+                "the word lists differ from the ones the session was started with".to_string(),
+            ));
+        }
+        game.replay(&self.rows)
+            .map_err(|(index, message)| invalid(format!("row {}: {}", index + 1, message)))
+    }
+
+    pub fn finished(&self) -> bool {
+        self.rows.len() >= self.config.max_attempts
+            || self
+                .rows
+                .last()
+                .is_some_and(|row| row.pattern.chars().all(|c| c == '2'))
+    }
+}
+
+#[test]
+fn test_session_round_trip() {
+    use crate::wordle_game::small_list;
+
+    let config = SolverConfig::default();
+    let game = config.build(small_list()).unwrap();
+    let mut session = Session::new(&config, &game);
+
+    let path = std::env::temp_dir().join(format!("wordle-session-{}.json", std::process::id()));
+    let row = Observation {
+        guess: "slate".to_string(),
+        pattern: "00222".to_string(),
+    };
+    session.push(row, &path).unwrap();
+    let loaded = Session::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, session);
+
+    let resumed = loaded.restore(&game).unwrap();
+    assert_eq!(resumed.possible_words(), vec!["crate", "irate"]);
+
+    let mut other = small_list();
+    other.include_str("extra", "grate\n");
+    let other = config.build(other).unwrap();
+    assert!(loaded.restore(&other).is_err());
+}
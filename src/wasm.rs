@@ -0,0 +1,59 @@
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::wordle_game::{Observation, ScoredGuess, WordleGame, pattern_from_guess};
+
+thread_local! {
+    static GAME: WordleGame = WordleGame::new(6);
+}
+
+#[derive(Serialize)]
+struct Suggestions {
+    remaining: usize,
+    suggestions: Vec<ScoredGuess>,
+}
+
+/// Replays a JS history (`[{guess, pattern}]`) on the built-in word list.
+fn with_history<T>(history: JsValue, f: impl FnOnce(&WordleGame) -> T) -> Result<T, JsError> {
+    let history: Vec<Observation> = if history.is_undefined() || history.is_null() {
+        vec![]
+    } else {
+        serde_wasm_bindgen::from_value(history)?
+    };
+    GAME.with(|game| {
+        let game = game.replay(&history).map_err(|(index, message)| {
+            JsError::new(&format!("history[{}]: {}", index, message))
+        })?;
+        Ok(f(&game))
+    })
+}
+
+/// `suggest([{guess: "raise", pattern: "01200"}], 5)` →
+/// `{remaining, suggestions: [{word, entropy}]}`
+///
+/// Throws when no word fits the history.
+#[wasm_bindgen]
+pub fn suggest(history: JsValue, top: Option<usize>) -> Result<JsValue, JsError> {
+    let suggestions = with_history(history, |game| {
+        if game.possible_words().is_empty() {
+            return Err(JsError::new("no word in the list fits the history"));
+        }
+        Ok(Suggestions {
+            remaining: game.possible_words().len(),
+            suggestions: game.ranked_guesses(top.unwrap_or(5)),
+        })
+    })??;
+    Ok(serde_wasm_bindgen::to_value(&suggestions)?)
+}
+
+/// `candidates([{guess, pattern}])` → the remaining possible answers.
+#[wasm_bindgen]
+pub fn candidates(history: JsValue) -> Result<Vec<String>, JsError> {
+    with_history(history, |game| game.possible_words())
+}
+
+/// `pattern("crane", "react")` → `"11201"`
+#[wasm_bindgen]
+pub fn pattern(guess: &str, answer: &str) -> String {
+    pattern_from_guess(guess, answer)
+}
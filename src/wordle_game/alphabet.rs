@@ -0,0 +1,134 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+const ENGLISH: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// The set of letters a word list is allowed to use.
+///
+/// Words are tokenized by grapheme cluster and every cluster is composed (NFC) and
+/// lowercased, so `n` + combining tilde and the precomposed `ñ` end up as the same letter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alphabet {
+    pub name: String,
+    letters: Vec<char>,
+}
+
+impl Alphabet {
+    pub fn english() -> Self {
+        Alphabet::from_letters("english", ENGLISH)
+    }
+
+    pub fn spanish() -> Self {
+        Alphabet::from_letters("spanish", &format!("{}ñ", ENGLISH))
+    }
+
+    pub fn german() -> Self {
+        Alphabet::from_letters("german", &format!("{}äöüß", ENGLISH))
+    }
+
+    pub fn polish() -> Self {
+        // q, v and x are not part of the native alphabet but show up in loanwords
+        Alphabet::from_letters("polish", &format!("{}ąćęłńóśźż", ENGLISH))
+    }
+
+    pub fn swedish() -> Self {
+        Alphabet::from_letters("swedish", &format!("{}åäö", ENGLISH))
+    }
+
+    /// Builds a custom alphabet from every letter in `letters` (case and order don't matter).
+    pub fn from_letters(name: &str, letters: &str) -> Self {
+        let mut table: Vec<char> = letters
+            .graphemes(true)
+            .filter_map(normalize_grapheme)
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        table.sort_unstable();
+        table.dedup();
+        Alphabet {
+            name: name.to_string(),
+            letters: table,
+        }
+    }
+
+    /// Looks up one of the built-in alphabets by name or ISO 639-1 code.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "en" | "english" => Some(Alphabet::english()),
+            "es" | "spanish" => Some(Alphabet::spanish()),
+            "de" | "german" => Some(Alphabet::german()),
+            "pl" | "polish" => Some(Alphabet::polish()),
+            "sv" | "swedish" => Some(Alphabet::swedish()),
+            _ => None,
+        }
+    }
+
+    /// Parses a built-in alphabet name or `custom:` followed by every letter of a custom one.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        match spec.strip_prefix("custom:") {
+            Some("") => Err("custom: needs the letters of the alphabet".to_string()),
+            Some(letters) => Ok(Alphabet::from_letters("custom", letters)),
+            None => Alphabet::from_name(spec).ok_or_else(|| {
+                format!(
+                    "unknown alphabet {:?}: use en, es, de, pl, sv or custom:<letters>",
+                    spec
+                )
+            }),
+        }
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        self.letters.binary_search(&c).is_ok()
+    }
+
+    /// Splits `word` into letters of this alphabet, or `None` if any grapheme isn't one.
+    pub fn tokenize(&self, word: &str) -> Option<Vec<char>> {
+        word.trim()
+            .graphemes(true)
+            .map(|g| normalize_grapheme(g).filter(|&c| self.contains(c)))
+            .collect()
+    }
+
+    /// Returns `word` in the canonical form used by the solver (lowercase, one `char` per letter).
+    pub fn normalize(&self, word: &str) -> Option<String> {
+        self.tokenize(word)
+            .map(|letters| letters.into_iter().collect())
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Alphabet::english()
+    }
+}
+
+fn normalize_grapheme(grapheme: &str) -> Option<char> {
+    let composed: String = grapheme.to_lowercase().nfc().collect();
+    let mut chars = composed.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_tokenize_composes_graphemes() {
+    let spanish = Alphabet::spanish();
+    assert_eq!(
+        spanish.normalize("Nin\u{0303}os"),
+        Some("niños".to_string())
+    );
+    assert_eq!(spanish.normalize("NIÑOS"), Some("niños".to_string()));
+    assert_eq!(Alphabet::english().normalize("niños"), None);
+    assert_eq!(
+        Alphabet::polish().tokenize("łąka").map(|l| l.len()),
+        Some(4)
+    );
+}
+
+#[test]
+fn test_parse_alphabet() {
+    assert_eq!(Alphabet::parse("EN"), Ok(Alphabet::english()));
+    assert!(Alphabet::parse("custom:abcç").unwrap().contains('ç'));
+    assert!(Alphabet::parse("fr").is_err());
+    assert!(Alphabet::parse("custom:").is_err());
+}
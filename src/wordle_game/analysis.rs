@@ -0,0 +1,217 @@
+use ahash::AHashMap;
+use std::io;
+
+use super::{ScoredGuess, WordleGame, entropy};
+
+/// One feedback pattern a guess can get and the candidates that would produce it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PatternBucket {
+    pub pattern: String,
+    /// Probability of getting this feedback.
+    pub probability: f64,
+    pub words: Vec<String>,
+}
+
+impl PatternBucket {
+    /// Bits of information gained when this feedback comes back; a certain bucket gives 0,
+    /// not the -0 that negating its log would print.
+    pub fn information(&self) -> f64 {
+        (1.0 / self.probability).log2()
+    }
+}
+
+/// Everything one search of `entrohpy_allgorithm` computed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GuessAnalysis {
+    /// Remaining candidate answers before the guess.
+    pub candidates: Vec<String>,
+    /// Every allowed guess, in the order the strategy prefers them.
+    pub guesses: Vec<ScoredGuess>,
+    /// How the candidates split by feedback for the chosen guess, largest bucket first.
+    pub distribution: Vec<PatternBucket>,
+}
+
+impl GuessAnalysis {
+    /// The guess the strategy picked.
+    pub fn best(&self) -> &ScoredGuess {
+        &self.guesses[0]
+    }
+
+    /// The only candidate left is played without scoring anything else.
+    pub(super) fn solved(candidates: Vec<String>) -> Self {
+        let word = candidates[0].clone();
+        GuessAnalysis {
+            guesses: vec![ScoredGuess {
+                word: word.clone(),
+                entropy: 0.0,
+                probability: 1.0,
+                expected_remaining: 1.0,
+                worst_case: 1,
+                buckets: 1,
+            }],
+            distribution: vec![PatternBucket {
+                pattern: "2".repeat(word.chars().count()),
+                probability: 1.0,
+                words: vec![word],
+            }],
+            candidates,
+        }
+    }
+}
+
+/// A guess scored against the remaining candidates next to the strategy's own pick.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GuessExplanation {
+    /// Remaining candidate answers before the guess.
+    pub candidates: usize,
+    /// Uncertainty about the answer before the guess, in bits.
+    pub uncertainty: f64,
+    pub guess: ScoredGuess,
+    /// 1-based position among the allowed guesses, `None` if it is not in the word list.
+    pub rank: Option<usize>,
+    pub distribution: Vec<PatternBucket>,
+    pub best: ScoredGuess,
+    pub best_distribution: Vec<PatternBucket>,
+}
+
+impl GuessExplanation {
+    /// The bucket `pattern` falls in for the explained guess.
+    pub fn feedback(&self, pattern: &str) -> Option<&PatternBucket> {
+        self.distribution.iter().find(|b| b.pattern == pattern)
+    }
+
+    /// The buckets `answer` falls in for the explained guess and for the best guess.
+    pub fn against(&self, answer: &str) -> (Option<&PatternBucket>, Option<&PatternBucket>) {
+        let contains = |b: &&PatternBucket| b.words.iter().any(|w| w == answer);
+        (
+            self.distribution.iter().find(contains),
+            self.best_distribution.iter().find(contains),
+        )
+    }
+}
+
+impl WordleGame {
+    /// Scores `guess` for the current state, whether or not it is in the word list.
+    pub fn explain(&self, guess: &str) -> Result<GuessExplanation, io::Error> {
+        let guess = self
+            .check_word(guess)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let analysis = self.entrohpy_allgorithm()?;
+        let probabilities = self.answer_probabilities(&analysis.candidates);
+
+        // with one candidate left the search only scores that word: rank the rest here
+        let ranked;
+        let guesses = if analysis.candidates.len() == 1 {
+            ranked = self.ranked(&analysis.candidates, &probabilities);
+            &ranked
+        } else {
+            &analysis.guesses
+        };
+        let rank = guesses.iter().position(|g| g.word == guess);
+        let scored = match rank {
+            Some(index) => guesses[index].clone(),
+            None => {
+                let probability = analysis
+                    .candidates
+                    .iter()
+                    .position(|w| *w == guess)
+                    .map_or(0.0, |index| probabilities[index]);
+                self.score_guess(&guess, probability, &analysis.candidates, &probabilities)
+            }
+        };
+
+        Ok(GuessExplanation {
+            candidates: analysis.candidates.len(),
+            uncertainty: entropy(&probabilities),
+            distribution: self.buckets_for(&guess, &analysis.candidates, &probabilities),
+            guess: scored,
+            rank: rank.map(|index| index + 1),
+            best: analysis.best().clone(),
+            best_distribution: analysis.distribution,
+        })
+    }
+
+    /// How the remaining candidates would split by feedback if `guess` were played.
+    pub fn bucket_distribution(&self, guess: &str) -> Vec<PatternBucket> {
+        let posible_words = self.possible_words();
+        let probabilities = self.answer_probabilities(&posible_words);
+        self.buckets_for(guess, &posible_words, &probabilities)
+    }
+
+    pub(super) fn buckets_for(
+        &self,
+        guess: &str,
+        posible_words: &[String],
+        probabilities: &[f64],
+    ) -> Vec<PatternBucket> {
+        let mut buckets: AHashMap<String, PatternBucket> = AHashMap::new();
+        for (word, p) in posible_words.iter().zip(probabilities) {
+            let pattern = self.pattern_from_guess(guess, word);
+            let bucket = buckets
+                .entry(pattern.clone())
+                .or_insert_with(|| PatternBucket {
+                    pattern,
+                    probability: 0.0,
+                    words: vec![],
+                });
+            bucket.probability += p;
+            bucket.words.push(word.clone());
+        }
+
+        let mut buckets: Vec<PatternBucket> = buckets.into_values().collect();
+        buckets.sort_by(|a, b| {
+            b.words
+                .len()
+                .cmp(&a.words.len())
+                .then_with(|| a.pattern.cmp(&b.pattern))
+        });
+        buckets
+    }
+}
+
+#[test]
+fn test_analysis_matches_best_guess() {
+    let game = super::small_game();
+
+    let analysis = game.entrohpy_allgorithm().unwrap();
+    assert_eq!(analysis.candidates.len(), 6);
+    assert_eq!(analysis.guesses.len(), 6);
+
+    let best = analysis.best();
+    let sizes: Vec<usize> = analysis
+        .distribution
+        .iter()
+        .map(|b| b.words.len())
+        .collect();
+    assert_eq!(sizes.iter().sum::<usize>(), 6);
+    assert_eq!(sizes.len(), best.buckets);
+    assert_eq!(sizes[0], best.worst_case);
+    assert_eq!(analysis.distribution, game.bucket_distribution(&best.word));
+}
+
+#[test]
+fn test_explain_compares_with_best_guess() {
+    let game = super::small_game();
+
+    let explanation = game.explain("PLATE").unwrap();
+    assert_eq!(explanation.guess.word, "plate");
+    assert!(explanation.rank.is_some());
+    assert!(explanation.guess.entropy <= explanation.best.entropy + 1e-9);
+
+    let bucket = explanation.feedback("22222").unwrap();
+    assert_eq!(bucket.words, vec!["plate"]);
+    assert!((bucket.information() - 6f64.log2()).abs() < 1e-9);
+    assert_eq!(explanation.against("plate").0, Some(bucket));
+    assert!(game.explain("toolong").is_err());
+
+    let mut game = game;
+    game.add_feedback("slate", "22222");
+    let explanation = game.explain("crane").unwrap();
+    assert_eq!(explanation.best.word, "slate");
+    assert_eq!(explanation.rank, Some(2));
+    assert!(explanation.guess.entropy.is_sign_positive());
+    assert!(explanation.uncertainty.is_sign_positive());
+}
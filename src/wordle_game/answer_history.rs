@@ -0,0 +1,103 @@
+use chrono::NaiveDate;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use super::Alphabet;
+
+/// Past NYT answers, stored one `YYYY-MM-DD word` pair per line.
+///
+/// The NYT never repeats an answer, so every word answered before today can be dropped
+/// from the candidate set.
+pub struct AnswerHistory {
+    pub path: PathBuf,
+    pub entries: Vec<(NaiveDate, String)>,
+}
+
+impl AnswerHistory {
+    /// Loads the history file, treating a missing file as an empty history. Words are
+    /// normalized with `alphabet` so they match the word list's.
+    pub fn load(path: impl AsRef<Path>, alphabet: &Alphabet) -> Result<Self, io::Error> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut entries = vec![];
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let entry = parse_entry(line, alphabet).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "{}:{}: expected `YYYY-MM-DD word`, got {:?}",
+                        path.display(),
+                        index + 1,
+                        line
+                    ),
+                )
+            })?;
+            entries.push(entry);
+        }
+        Ok(AnswerHistory { path, entries })
+    }
+
+    /// Answers from puzzles published before `date`.
+    pub fn answers_before(&self, date: NaiveDate) -> impl Iterator<Item = &str> {
+        self.entries
+            .iter()
+            .filter(move |(d, _)| *d < date)
+            .map(|(_, word)| word.as_str())
+    }
+
+    /// Appends `word` as the answer for `date` unless that date is already recorded.
+    pub fn record(&mut self, date: NaiveDate, word: &str) -> Result<(), io::Error> {
+        if self.entries.iter().any(|(d, _)| *d == date) {
+            return Ok(());
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{} {}", date.format("%Y-%m-%d"), word)?;
+        self.entries.push((date, word.to_string()));
+        Ok(())
+    }
+}
+
+fn parse_entry(line: &str, alphabet: &Alphabet) -> Option<(NaiveDate, String)> {
+    let mut parts = line
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty());
+    let date = NaiveDate::parse_from_str(parts.next()?, "%Y-%m-%d").ok()?;
+    let word = alphabet.normalize(parts.next()?)?;
+    match parts.next() {
+        None => Some((date, word)),
+        Some(_) => None,
+    }
+}
+
+#[test]
+fn test_parse_entry() {
+    let english = Alphabet::english();
+    let date = NaiveDate::from_ymd_opt(2021, 6, 19).unwrap();
+    assert_eq!(
+        parse_entry("2021-06-19 CIGAR", &english),
+        Some((date, "cigar".to_string()))
+    );
+    assert_eq!(
+        parse_entry("2021-06-19,cigar", &english),
+        Some((date, "cigar".to_string()))
+    );
+    assert_eq!(parse_entry("cigar 2021-06-19", &english), None);
+    assert_eq!(parse_entry("2021-06-19 caña", &english), None);
+    assert_eq!(
+        parse_entry("2021-06-19 CAN\u{303}A", &Alphabet::spanish()),
+        Some((date, "caña".to_string()))
+    );
+}
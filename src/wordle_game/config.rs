@@ -0,0 +1,57 @@
+use std::io;
+use std::path::PathBuf;
+
+use super::{AnswerHistory, ScoreCurve, Sigmoid, Strategy, WordList, WordPriors, WordleGame};
+
+/// Options shared by every front end that builds a [`WordleGame`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolverConfig {
+    pub max_attempts: usize,
+    /// Past answers (`YYYY-MM-DD word` per line) to drop from the candidates.
+    pub answer_history: Option<PathBuf>,
+    /// Word commonness scores (`word score` per line) used as answer priors.
+    pub frequencies: Option<PathBuf>,
+    pub sigmoid: Sigmoid,
+    pub strategy: Strategy,
+    /// Fitted expected-guesses curve for [`Strategy::ExpectedScore`]; defaults when unset.
+    pub score_curve: Option<PathBuf>,
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        SolverConfig {
+            max_attempts: 6,
+            answer_history: None,
+            frequencies: None,
+            sigmoid: Sigmoid::default(),
+            strategy: Strategy::default(),
+            score_curve: None,
+        }
+    }
+}
+
+impl SolverConfig {
+    pub fn build(&self, list: WordList) -> Result<WordleGame, io::Error> {
+        let mut game = WordleGame::from_word_list(self.max_attempts, list);
+        self.apply(&mut game)?;
+        Ok(game)
+    }
+
+    /// Loads the configured answer history and priors into an existing game.
+    pub fn apply(&self, game: &mut WordleGame) -> Result<(), io::Error> {
+        game.strategy = self.strategy;
+        game.score_curve = match &self.score_curve {
+            Some(path) => ScoreCurve::load(path)?,
+            None => ScoreCurve::default(),
+        };
+        if let Some(path) = &self.answer_history {
+            let today = chrono::Local::now().date_naive();
+            game.exclude_answers(AnswerHistory::load(path, &game.alphabet)?.answers_before(today));
+        }
+        if let Some(path) = &self.frequencies {
+            game.set_priors(WordPriors::load(path, self.sigmoid, &game.alphabet)?);
+        }
+        Ok(())
+    }
+}
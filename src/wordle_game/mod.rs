@@ -1,29 +1,84 @@
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 // use rand::prelude::IndexedRandom;
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
 use rayon::prelude::*;
 use std::io::{self};
 
+mod alphabet;
+mod analysis;
+mod answer_history;
+mod config;
+mod priors;
+mod review;
+mod share;
+mod simulate;
+mod strategy;
+mod word_list;
+pub use alphabet::Alphabet;
+pub use analysis::{GuessAnalysis, GuessExplanation, PatternBucket};
+pub use answer_history::AnswerHistory;
+pub use config::SolverConfig;
+pub use priors::{Sigmoid, WordPriors};
+pub use review::RowReview;
+pub use share::{ShareGrid, puzzle_number};
+pub use simulate::{GameResult, simulate};
+pub use strategy::{ScoreCurve, Strategy};
+pub use word_list::{Diagnostic, WordList};
+
+#[derive(Clone)]
 pub struct WordleGame {
     // target_word: String,
     pub correct_gussed_characters: Vec<CharGuess>,
     // attempts: usize,
     pub max_attempts: usize,
+    pub alphabet: Alphabet,
+    pub word_length: usize,
+    pub strategy: Strategy,
+    pub score_curve: ScoreCurve,
     words: Vec<String>,
+    // still allowed as guesses, but can no longer be the answer
+    excluded_answers: AHashSet<String>,
+    priors: Option<WordPriors>,
 }
 #[derive(Debug, Clone)]
 pub struct CharGuess {
-    pub c: u8,
+    pub c: char,
     pub feedback: u8,    // Green, Yellow, Gray
     pub position: usize, // actual index of the guess
 }
 
+/// One allowed guess scored against the remaining candidates.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScoredGuess {
+    pub word: String,
+    /// Expected information from its feedback, in bits.
+    pub entropy: f64,
+    /// Probability that this guess is the answer.
+    pub probability: f64,
+    /// Expected number of candidates consistent with its feedback.
+    pub expected_remaining: f64,
+    /// Candidates left after the least informative feedback.
+    pub worst_case: usize,
+    /// Number of distinct feedback patterns it can get.
+    pub buckets: usize,
+}
+
+/// A played word and the feedback it got, e.g. "raise" / "01200".
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Observation {
+    pub guess: String,
+    pub pattern: String,
+}
+
 impl WordleGame {
     pub fn new(max_attempts: usize) -> Self {
-        const WORD_LIST: &str = include_str!("possible_anwsers.txt");
-        let words: Vec<String> = WORD_LIST
-            .lines()
-            .filter_map(|line| line.trim().to_string().into())
-            .collect();
+        WordleGame::from_word_list(max_attempts, WordList::builtin_answers())
+    }
+
+    /// Builds a game over a loaded word list, e.g. the answers of a Spanish Wordle clone.
+    pub fn from_word_list(max_attempts: usize, list: WordList) -> Self {
         // let mut rng = rand::rngs::ThreadRng::default();
         // let random_word = words.choose(&mut rng).expect("No words available");
         // let target_word = random_word.clone();
@@ -32,26 +87,125 @@ impl WordleGame {
             // attempts: 0,
             max_attempts,
             correct_gussed_characters: vec![],
-            words,
+            alphabet: list.alphabet,
+            word_length: list.word_length,
+            strategy: Strategy::default(),
+            score_curve: ScoreCurve::default(),
+            words: list.words,
+            excluded_answers: AHashSet::new(),
+            priors: None,
+        }
+    }
+
+    /// Weights candidates by how common they are instead of treating them as equally likely.
+    pub fn set_priors(&mut self, priors: WordPriors) {
+        self.priors = Some(priors);
+    }
+
+    /// Removes already used answers (e.g. from an [`AnswerHistory`]) from the candidate set.
+    pub fn exclude_answers<'a>(&mut self, answers: impl IntoIterator<Item = &'a str>) {
+        self.excluded_answers
+            .extend(answers.into_iter().map(str::to_string));
+    }
+
+    /// Every allowed guess; the candidates are the ones consistent with the feedback so far.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Forgets every recorded guess so a new game can start from the full candidate set.
+    pub fn reset(&mut self) {
+        self.correct_gussed_characters.clear();
+    }
+
+    pub fn add_guess(&mut self, guess: CharGuess) {
+        let is_duplicate = self.correct_gussed_characters.iter().any(|g| {
+            g.c == guess.c && g.feedback == guess.feedback && g.position == guess.position
+        });
+
+        if !is_duplicate {
+            self.correct_gussed_characters.push(guess);
+        }
+    }
+
+    /// Records the feedback for a played word, with `pattern` written as digits ("20110").
+    pub fn add_feedback(&mut self, guess_word: &str, pattern: &str) {
+        for (position, (c, fb)) in guess_word.chars().zip(pattern.chars()).enumerate() {
+            self.add_guess(CharGuess {
+                c,
+                feedback: fb.to_digit(10).unwrap_or(0) as u8,
+                position,
+            });
+        }
+    }
+
+    /// Normalizes a word and checks it against this game's alphabet and word length.
+    pub fn check_word(&self, word: &str) -> Result<String, String> {
+        let word = self.alphabet.normalize(word).ok_or_else(|| {
+            format!(
+                "{:?} uses letters outside the {} alphabet",
+                word, self.alphabet.name
+            )
+        })?;
+        if word.chars().count() != self.word_length {
+            return Err(format!(
+                "{:?} has {} letters, expected {}",
+                word,
+                word.chars().count(),
+                self.word_length
+            ));
+        }
+        Ok(word)
+    }
+
+    /// Normalizes a played word and checks it and its pattern against this game's alphabet
+    /// and word length.
+    pub fn check_observation(&self, guess: &str, pattern: &str) -> Result<Observation, String> {
+        let guess = self.check_word(guess)?;
+        if pattern.chars().count() != self.word_length
+            || !pattern.chars().all(|c| matches!(c, '0' | '1' | '2'))
+        {
+            return Err(format!(
+                "pattern {:?} must be {} digits of 0, 1 or 2",
+                pattern, self.word_length
+            ));
+        }
+        Ok(Observation {
+            guess,
+            pattern: pattern.to_string(),
+        })
+    }
+
+    /// A fresh copy of this game with `history` validated and applied; errors carry the
+    /// index of the offending observation.
+    pub fn replay(&self, history: &[Observation]) -> Result<WordleGame, (usize, String)> {
+        let mut game = self.clone();
+        game.reset();
+        for (index, observation) in history.iter().enumerate() {
+            let observation = game
+                .check_observation(&observation.guess, &observation.pattern)
+                .map_err(|message| (index, message))?;
+            game.add_feedback(&observation.guess, &observation.pattern);
         }
+        Ok(game)
     }
 
     fn is_word_valid(&self, word: &str) -> bool {
         for guess in &self.correct_gussed_characters {
-            let c = guess.c as char;
+            let c = guess.c;
             let feedback = guess.feedback;
             let pos = guess.position;
 
             match feedback {
-                2 => {
-                    if word.chars().nth(pos).unwrap_or('_') != c {
-                        return false;
-                    }
+                2 if word.chars().nth(pos) != Some(c) => {
+                    return false;
                 }
-                1 => {
-                    if !word.contains(c) || word.chars().nth(pos).unwrap_or('_') == c {
-                        return false;
-                    }
+                1 if !word.contains(c) || word.chars().nth(pos) == Some(c) => {
+                    return false;
+                }
+                // gray where it stands, even when another copy of the letter is green or yellow
+                0 if word.chars().nth(pos) == Some(c) => {
+                    return false;
                 }
                 0 => {
                     let appeared_in_other_guess = self
@@ -70,97 +224,175 @@ impl WordleGame {
     }
 
     pub fn pattern_from_guess(&self, guess: &str, answer: &str) -> String {
-        let mut pattern = vec![0; 5];
-        let mut answer_chars: Vec<char> = answer.chars().collect();
-        let guess_chars: Vec<char> = guess.chars().collect();
-
-        // First pass: mark greens (2)
-        for i in 0..5 {
-            if guess_chars[i] == answer_chars[i] {
-                pattern[i] = 2;
-                answer_chars[i] = '_'; // Mark as used
-            }
-        }
-
-        // Second pass: mark yellows (1)
-        for i in 0..5 {
-            if pattern[i] == 0 {
-                if let Some(pos) = answer_chars.iter().position(|&c| c == guess_chars[i]) {
-                    pattern[i] = 1;
-                    answer_chars[pos] = '_'; // Mark as used
-                }
-            }
-        }
+        pattern_from_guess(guess, answer)
+    }
 
-        pattern
+    /// Remaining candidate answers consistent with every recorded guess.
+    pub fn possible_words(&self) -> Vec<String> {
+        self.words
             .iter()
-            .map(|&n| char::from_digit(n, 10).unwrap())
+            .filter(|&word| !self.excluded_answers.contains(word) && self.is_word_valid(word))
+            .cloned()
             .collect()
     }
 
-    pub fn entrohpy_allgorithm(&self) -> Result<(String, f64), io::Error> {
-        let posible_words: Vec<String> = self
-            .words
-            .iter()
-            .filter(|&word| self.is_word_valid(word))
-            .cloned()
-            .collect();
-        let total_words = posible_words.len();
+    /// Probability of each candidate being the answer: uniform, or proportional to the
+    /// prior weights when a frequency file was loaded.
+    fn answer_probabilities(&self, posible_words: &[String]) -> Vec<f64> {
+        let weights: Vec<f64> = match &self.priors {
+            Some(priors) => posible_words.iter().map(|w| priors.weight(w)).collect(),
+            None => vec![1.0; posible_words.len()],
+        };
+        let total: f64 = weights.iter().sum();
+        weights.iter().map(|w| w / total).collect()
+    }
+
+    /// Uncertainty about the answer in bits (log2 of the candidate count without priors).
+    pub fn candidate_entropy(&self) -> f64 {
+        entropy(&self.answer_probabilities(&self.possible_words()))
+    }
 
-        // Debug: Print remaining possible words
-        println!("Remaining possible words: {}", posible_words.len());
+    /// Scores every allowed guess against the remaining candidates and picks the best one
+    /// for the strategy.
+    pub fn entrohpy_allgorithm(&self) -> Result<GuessAnalysis, io::Error> {
+        let posible_words = self.possible_words();
+        let total_words = posible_words.len();
+        let _span = tracing::debug_span!("guess", candidates = total_words).entered();
 
-        if total_words == 1 {
-            return Ok((posible_words[0].clone(), 0.0));
+        if total_words == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "no candidate word is consistent with the feedback",
+            ));
+        } else if total_words == 1 {
+            tracing::debug!(word = %posible_words[0], "single candidate left");
+            return Ok(GuessAnalysis::solved(posible_words));
         } else if total_words <= 20 {
-            println!("Words: {:?}", posible_words);
+            tracing::trace!(words = ?posible_words, "remaining candidates");
         }
-        let entropies: Vec<(String, f64)> = self
-            .words
-            .par_iter()
-            .map(|word| {
-                let mut frequency_map: AHashMap<String, usize> = AHashMap::new();
-                for w in &posible_words {
-                    let pattern = self.pattern_from_guess(word, w);
-                    *frequency_map.entry(pattern).or_insert(0) += 1;
-                }
-
-                let entropy = frequency_map
-                    .values()
-                    .map(|&count| {
-                        if total_words == 0 {
-                            return 0.0;
-                        }
-                        let p = count as f64 / total_words as f64;
-                        if p > 0.0 { -p * p.log2() } else { 0.0 }
-                    })
-                    .sum();
-
-                (word.clone(), entropy)
-            })
-            .collect();
+        let probabilities = self.answer_probabilities(&posible_words);
+        let entropies = self.ranked(&posible_words, &probabilities);
 
-        // Debug: Show top 5 candidates
-        let mut sorted_entropies = entropies.clone();
-        sorted_entropies.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
-        println!("Top 5 entropy words:");
-        for (word, entropy) in sorted_entropies.iter().take(5) {
-            println!("  {}: {:.4}", word, entropy);
+        if tracing::enabled!(tracing::Level::DEBUG) {
+            let mut sorted_entropies = entropies.clone();
+            sorted_entropies.sort_by(|a, b| compare_guesses(b, a));
+            for (rank, guess) in sorted_entropies.iter().take(5).enumerate() {
+                tracing::debug!(
+                    rank = rank + 1,
+                    word = %guess.word,
+                    entropy = guess.entropy,
+                    probability = guess.probability,
+                    "top entropy guess"
+                );
+            }
         }
 
-        let (best_word, best_entropy) = entropies
+        let best = &entropies[0];
+        tracing::debug!(
+            word = %best.word,
+            entropy = best.entropy,
+            worst_case = best.worst_case,
+            strategy = self.strategy.name(),
+            "best guess"
+        );
+        let distribution = self.buckets_for(&best.word, &posible_words, &probabilities);
+        Ok(GuessAnalysis {
+            candidates: posible_words,
+            guesses: entropies,
+            distribution,
+        })
+    }
+
+    /// The `limit` best guesses for the current state in the order the strategy prefers them.
+    pub fn ranked_guesses(&self, limit: usize) -> Vec<ScoredGuess> {
+        let posible_words = self.possible_words();
+        let probabilities = self.answer_probabilities(&posible_words);
+        let mut entropies = self.ranked(&posible_words, &probabilities);
+        entropies.truncate(limit);
+        entropies
+    }
+
+    /// Every allowed guess, best first for the strategy.
+    fn ranked(&self, posible_words: &[String], probabilities: &[f64]) -> Vec<ScoredGuess> {
+        let mut entropies = self.score_guesses(posible_words, probabilities);
+        let uncertainty = entropy(probabilities);
+        entropies.sort_by(|a, b| self.compare_by_strategy(b, a, uncertainty));
+        entropies
+    }
+
+    /// Scores every allowed guess against the remaining candidates.
+    fn score_guesses(&self, posible_words: &[String], probabilities: &[f64]) -> Vec<ScoredGuess> {
+        let answer_probability: AHashMap<&str, f64> = posible_words
             .iter()
-            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
-            .unwrap()
-            .clone();
+            .map(String::as_str)
+            .zip(probabilities.iter().copied())
+            .collect();
 
-        println!("Best word: {}, Entropy: {}", best_word, best_entropy);
-        Ok((best_word, best_entropy))
+        #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+        let guesses = self.words.par_iter();
+        #[cfg(not(all(feature = "parallel", not(target_arch = "wasm32"))))]
+        let guesses = self.words.iter();
+        guesses
+            .map(|word| {
+                let probability = answer_probability
+                    .get(word.as_str())
+                    .copied()
+                    .unwrap_or(0.0);
+                self.score_guess(word, probability, posible_words, probabilities)
+            })
+            .collect()
     }
 
+    /// Scores one guess, which is the answer with `probability`.
+    fn score_guess(
+        &self,
+        word: &str,
+        probability: f64,
+        posible_words: &[String],
+        probabilities: &[f64],
+    ) -> ScoredGuess {
+        // pattern -> (probability, candidate count)
+        let mut frequency_map: AHashMap<String, (f64, usize)> = AHashMap::new();
+        for (w, p) in posible_words.iter().zip(probabilities) {
+            let pattern = self.pattern_from_guess(word, w);
+            let bucket = frequency_map.entry(pattern).or_insert((0.0, 0));
+            bucket.0 += p;
+            bucket.1 += 1;
+        }
+
+        let buckets: Vec<f64> = frequency_map.values().map(|b| b.0).collect();
+        ScoredGuess {
+            word: word.to_string(),
+            entropy: entropy(&buckets),
+            probability,
+            expected_remaining: frequency_map.values().map(|&(p, n)| p * n as f64).sum(),
+            worst_case: frequency_map.values().map(|b| b.1).max().unwrap_or(0),
+            buckets: buckets.len(),
+        }
+    }
 
+    /// Orders two scored guesses so that the one the strategy prefers compares greater.
+    fn compare_by_strategy(
+        &self,
+        a: &ScoredGuess,
+        b: &ScoredGuess,
+        uncertainty: f64,
+    ) -> std::cmp::Ordering {
+        match self.strategy {
+            Strategy::MaxEntropy => compare_guesses(a, b),
+            Strategy::ExpectedScore => {
+                let score = |guess: &ScoredGuess| {
+                    self.score_curve
+                        .expected_score(guess.probability, uncertainty, guess.entropy)
+                };
+                // lower expected score is better
+                score(b)
+                    .total_cmp(&score(a))
+                    .then_with(|| a.probability.total_cmp(&b.probability))
+            }
+        }
+    }
 
-    
     // fn guess(&mut self, guessed_word: &str) -> Result<String, String> {
     //     if guessed_word.len() != 5 {
     //         return Err("Please enter a valid 5-letter word.".to_string());
@@ -257,3 +489,77 @@ impl WordleGame {
     //     ))
     // }
 }
+
+/// Feedback for playing `guess` against `answer`, one digit per letter:
+/// 2 = green, 1 = yellow, 0 = gray (e.g. "20110").
+pub fn pattern_from_guess(guess: &str, answer: &str) -> String {
+    let guess_chars: Vec<char> = guess.chars().collect();
+    let mut answer_chars: Vec<Option<char>> = answer.chars().map(Some).collect();
+    let mut pattern = vec![0; guess_chars.len()];
+
+    // First pass: mark greens (2)
+    for i in 0..guess_chars.len().min(answer_chars.len()) {
+        if answer_chars[i] == Some(guess_chars[i]) {
+            pattern[i] = 2;
+            answer_chars[i] = None; // Mark as used
+        }
+    }
+
+    // Second pass: mark yellows (1)
+    for i in 0..guess_chars.len() {
+        if pattern[i] == 0
+            && let Some(pos) = answer_chars.iter().position(|&c| c == Some(guess_chars[i]))
+        {
+            pattern[i] = 1;
+            answer_chars[pos] = None; // Mark as used
+        }
+    }
+
+    pattern
+        .iter()
+        .map(|&n| char::from_digit(n, 10).unwrap())
+        .collect()
+}
+
+/// Shannon entropy in bits of a probability distribution.
+pub fn entropy(probabilities: &[f64]) -> f64 {
+    let bits: f64 = probabilities
+        .iter()
+        .map(|&p| if p > 0.0 { -p * p.log2() } else { 0.0 })
+        .sum();
+    // a certain outcome (and an empty sum) gives -0, which would print as "-0.0000 bits"
+    bits + 0.0
+}
+
+/// Orders guesses by entropy, breaking (near) ties in favour of the likelier answer so the
+/// final guesses go to common words.
+fn compare_guesses(a: &ScoredGuess, b: &ScoredGuess) -> std::cmp::Ordering {
+    if (a.entropy - b.entropy).abs() > 1e-9 {
+        a.entropy.total_cmp(&b.entropy)
+    } else {
+        a.probability.total_cmp(&b.probability)
+    }
+}
+
+/// The six-word list the unit tests play with.
+#[cfg(test)]
+pub(crate) fn small_list() -> WordList {
+    let mut list = WordList::new(5, Alphabet::english());
+    list.include_str("test", "crane\ncrate\ntrace\nslate\nplate\nirate\n");
+    list
+}
+
+#[cfg(test)]
+pub(crate) fn small_game() -> WordleGame {
+    WordleGame::from_word_list(6, small_list())
+}
+
+#[test]
+fn test_gray_letter_excludes_its_position() {
+    let mut list = WordList::new(5, Alphabet::english());
+    list.include_str("test", "crate\nerase\n");
+    let mut game = WordleGame::from_word_list(6, list);
+    // the last e of "eerie" is green and the r yellow: the first two e are gray
+    game.add_feedback("eerie", "00102");
+    assert_eq!(game.possible_words(), vec!["crate"]);
+}
@@ -0,0 +1,127 @@
+use ahash::AHashMap;
+use std::io;
+use std::path::Path;
+
+use super::Alphabet;
+
+/// Maps a word's commonness rank onto a prior weight in (0, 1).
+///
+/// Words are sorted from least to most common and placed on [0, 1]; `midpoint` is the
+/// position that gets weight 0.5 and `steepness` controls how sharply rare words fall off.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sigmoid {
+    pub midpoint: f64,
+    pub steepness: f64,
+}
+
+impl Default for Sigmoid {
+    fn default() -> Self {
+        Sigmoid {
+            midpoint: 0.3,
+            steepness: 10.0,
+        }
+    }
+}
+
+impl Sigmoid {
+    pub fn weight(&self, position: f64) -> f64 {
+        1.0 / (1.0 + (-self.steepness * (position - self.midpoint)).exp())
+    }
+}
+
+/// Prior probability weights for candidate answers, built from a word frequency file.
+#[derive(Debug, Clone)]
+pub struct WordPriors {
+    weights: AHashMap<String, f64>,
+    // weight of words missing from the frequency file, which rank below every listed word
+    unlisted_weight: f64,
+}
+
+impl WordPriors {
+    pub fn from_frequencies(frequencies: AHashMap<String, f64>, sigmoid: Sigmoid) -> Self {
+        let mut ranked: Vec<(String, f64)> = frequencies.into_iter().collect();
+        ranked.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+
+        // rank 0 is reserved for unlisted words
+        let last_rank = ranked.len().max(1) as f64;
+        let weights = ranked
+            .into_iter()
+            .enumerate()
+            .map(|(rank, (word, _))| (word, sigmoid.weight((rank + 1) as f64 / last_rank)))
+            .collect();
+        WordPriors {
+            weights,
+            unlisted_weight: sigmoid.weight(0.0),
+        }
+    }
+
+    /// Reads `word score` lines (whitespace, comma or tab separated) where higher scores
+    /// mean more common words. Words are normalized with `alphabet`; the ones outside it
+    /// can never be answers and are skipped.
+    pub fn load(
+        path: impl AsRef<Path>,
+        sigmoid: Sigmoid,
+        alphabet: &Alphabet,
+    ) -> Result<Self, io::Error> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        let mut frequencies = AHashMap::new();
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|part| !part.is_empty());
+            let entry = match (parts.next(), parts.next().map(str::parse::<f64>)) {
+                (Some(word), Some(Ok(score))) if score.is_finite() => (word, score),
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "{}:{}: expected `word score`, got {:?}",
+                            path.display(),
+                            index + 1,
+                            line
+                        ),
+                    ));
+                }
+            };
+            if let Some(word) = alphabet.normalize(entry.0) {
+                frequencies.insert(word, entry.1);
+            }
+        }
+        Ok(WordPriors::from_frequencies(frequencies, sigmoid))
+    }
+
+    pub fn weight(&self, word: &str) -> f64 {
+        self.weights
+            .get(word)
+            .copied()
+            .unwrap_or(self.unlisted_weight)
+    }
+}
+
+#[test]
+fn test_common_words_weigh_more() {
+    let frequencies = [("crane", 5.0e-6), ("craal", 1.0e-9), ("slate", 2.0e-6)]
+        .into_iter()
+        .map(|(w, f)| (w.to_string(), f))
+        .collect();
+    let priors = WordPriors::from_frequencies(frequencies, Sigmoid::default());
+    assert!(priors.weight("crane") > priors.weight("slate"));
+    assert!(priors.weight("slate") > priors.weight("craal"));
+    assert!(priors.weight("craal") > priors.weight("zzzzz"));
+}
+
+#[test]
+fn test_load_normalizes_words() {
+    let path = std::env::temp_dir().join("wordle-solver-test-priors.txt");
+    std::fs::write(&path, "CAN\u{303}A 5e-6\nperro 1e-6\nqu'est 1e-3\n").unwrap();
+    let priors = WordPriors::load(&path, Sigmoid::default(), &Alphabet::spanish()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(priors.weight("caña") > priors.weight("perro"));
+    assert!(!priors.weights.contains_key("qu'est"));
+}
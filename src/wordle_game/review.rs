@@ -0,0 +1,145 @@
+use std::io;
+
+use super::{GameResult, Observation, ScoredGuess, WordleGame};
+
+/// How one played row compares with what the solver expected and would have played.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RowReview {
+    pub guess: String,
+    pub pattern: String,
+    pub candidates_before: usize,
+    pub candidates_after: usize,
+    /// Entropy of the played guess.
+    pub expected_bits: f64,
+    /// Information its feedback actually carried.
+    pub actual_bits: f64,
+    /// The solver's preferred guess at this point.
+    pub best: ScoredGuess,
+    /// Candidates the preferred guess would have left, when the answer is known.
+    pub best_after: Option<usize>,
+    /// 0-100: the preferred guess's expected total guesses over the played one's.
+    pub skill: f64,
+    /// 0-100: share of the feedback outcomes that would have left more candidates, 50 is average.
+    pub luck: f64,
+}
+
+impl GameResult {
+    pub fn observations(&self) -> Vec<Observation> {
+        self.guesses
+            .iter()
+            .zip(&self.patterns)
+            .map(|(guess, pattern)| Observation {
+                guess: guess.clone(),
+                pattern: pattern.clone(),
+            })
+            .collect()
+    }
+}
+
+impl WordleGame {
+    /// Replays `rows` from the start and scores every one of them. `answer` defaults to the
+    /// last guess when the game was solved.
+    pub fn review(
+        &self,
+        rows: &[Observation],
+        answer: Option<&str>,
+    ) -> Result<Vec<RowReview>, io::Error> {
+        let invalid = |row: usize, message: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("row {}: {}", row + 1, message),
+            )
+        };
+        let solved = rows
+            .last()
+            .filter(|row| row.pattern.chars().all(|c| c == '2'))
+            .map(|row| row.guess.as_str());
+        let answer = answer
+            .or(solved)
+            .map(|answer| self.check_word(answer))
+            .transpose()
+            .map_err(|message| {
+                io::Error::new(io::ErrorKind::InvalidInput, format!("answer: {}", message))
+            })?;
+
+        let mut game = self.clone();
+        game.reset();
+        let mut reviews = Vec::with_capacity(rows.len());
+        for (index, row) in rows.iter().enumerate() {
+            let row = game
+                .check_observation(&row.guess, &row.pattern)
+                .map_err(|message| invalid(index, message))?;
+            let explanation = game.explain(&row.guess)?;
+            let bucket = explanation.feedback(&row.pattern).ok_or_else(|| {
+                invalid(
+                    index,
+                    format!("{} is impossible after the earlier rows", row.pattern),
+                )
+            })?;
+
+            let score = |guess: &ScoredGuess| {
+                game.score_curve.expected_score(
+                    guess.probability,
+                    explanation.uncertainty,
+                    guess.entropy,
+                )
+            };
+            let (guess, best) = (&explanation.guess, &explanation.best);
+            let skill = 100.0 * (score(best) / score(guess)).min(1.0);
+
+            let actual = bucket.words.len();
+            let luck: f64 = explanation
+                .distribution
+                .iter()
+                .map(|b| match b.words.len() {
+                    n if n > actual => b.probability,
+                    n if n == actual => b.probability / 2.0,
+                    _ => 0.0,
+                })
+                .sum();
+
+            reviews.push(RowReview {
+                guess: row.guess.clone(),
+                pattern: row.pattern.clone(),
+                candidates_before: explanation.candidates,
+                candidates_after: actual,
+                expected_bits: guess.entropy,
+                actual_bits: bucket.information(),
+                best_after: answer
+                    .as_deref()
+                    .and_then(|answer| explanation.against(answer).1)
+                    .map(|b| b.words.len()),
+                best: best.clone(),
+                skill,
+                luck: 100.0 * luck,
+            });
+            game.add_feedback(&row.guess, &row.pattern);
+        }
+        Ok(reviews)
+    }
+}
+
+#[test]
+fn test_review_scores_rows() {
+    let mut game = super::small_game();
+
+    let result = game.play_against("plate", None).unwrap();
+    game.reset();
+    let reviews = game.review(&result.observations(), None).unwrap();
+    assert_eq!(reviews.len(), result.guesses.len());
+    assert_eq!(reviews[0].candidates_before, 6);
+    assert!((reviews[0].skill - 100.0).abs() < 1e-9);
+    assert_eq!(reviews.last().unwrap().candidates_after, 1);
+    assert!(reviews.iter().all(|r| (0.0..=100.0).contains(&r.luck)));
+
+    let upper = game.review(&result.observations(), Some("PLATE")).unwrap();
+    assert_eq!(upper[0].best_after, reviews[0].best_after);
+    assert!(upper[0].best_after.is_some());
+
+    let rows = [Observation {
+        guess: "crane".to_string(),
+        pattern: "22221".to_string(),
+    }];
+    assert!(game.review(&rows, None).is_err());
+}
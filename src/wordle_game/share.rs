@@ -0,0 +1,276 @@
+use ahash::AHashMap;
+use chrono::NaiveDate;
+#[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+use rayon::prelude::*;
+
+use super::{Observation, pattern_from_guess};
+
+/// The emoji grid Wordle players share, e.g. "Wordle 1,234 4/6" followed by one row of
+/// tiles per guess.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShareGrid {
+    pub puzzle: Option<u32>,
+    pub max_attempts: usize,
+    /// Marked with `*` after the score.
+    pub hard_mode: bool,
+    /// One pattern of digits per row (2 = green, 1 = yellow, 0 = gray).
+    pub patterns: Vec<String>,
+}
+
+/// NYT puzzle number for `date`; puzzle 0 was on 2021-06-19.
+pub fn puzzle_number(date: NaiveDate) -> Option<u32> {
+    let first = NaiveDate::from_ymd_opt(2021, 6, 19)?;
+    u32::try_from((date - first).num_days()).ok()
+}
+
+fn tile_feedback(tile: char) -> Option<char> {
+    match tile {
+        // green, or orange with high contrast
+        '\u{1F7E9}' | '\u{1F7E7}' => Some('2'),
+        // yellow, or blue with high contrast
+        '\u{1F7E8}' | '\u{1F7E6}' => Some('1'),
+        // dark and light mode blanks
+        '\u{2B1B}' | '\u{2B1C}' => Some('0'),
+        _ => None,
+    }
+}
+
+impl ShareGrid {
+    pub fn from_rows(rows: &[Observation], puzzle: Option<u32>, max_attempts: usize) -> Self {
+        ShareGrid {
+            puzzle,
+            max_attempts,
+            hard_mode: false,
+            patterns: rows.iter().map(|row| row.pattern.clone()).collect(),
+        }
+    }
+
+    /// Parses share text; lines other than the header and the tile rows are ignored.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut grid = ShareGrid {
+            puzzle: None,
+            max_attempts: 6,
+            hard_mode: false,
+            patterns: vec![],
+        };
+        let mut score = None;
+        for line in text.lines().map(str::trim) {
+            if let Some(header) = line.strip_prefix("Wordle ") {
+                score = Some(grid.parse_header(header)?);
+                continue;
+            }
+            // some platforms add a variation selector after the blank squares
+            let tiles: Option<String> = line
+                .chars()
+                .filter(|&c| c != '\u{FE0F}')
+                .map(tile_feedback)
+                .collect();
+            match tiles {
+                Some(pattern) if !pattern.is_empty() => grid.patterns.push(pattern),
+                _ => {}
+            }
+        }
+
+        if grid.patterns.is_empty() {
+            return Err("no rows of tiles found".to_string());
+        }
+        let length = grid.patterns[0].len();
+        if let Some(row) = grid.patterns.iter().position(|p| p.len() != length) {
+            return Err(format!("row {} has a different number of tiles", row + 1));
+        }
+        let solved_rows = grid
+            .patterns
+            .iter()
+            .position(|p| p.chars().all(|c| c == '2'));
+        let rows = grid.patterns.len();
+        if solved_rows.is_some_and(|row| row + 1 != rows) {
+            return Err("rows follow the solved row".to_string());
+        }
+        match (score, solved_rows) {
+            (Some(Some(guesses)), Some(_)) if guesses == rows => {}
+            (Some(None), None) if rows == grid.max_attempts => {}
+            (None, _) => {}
+            _ => return Err("the score in the header does not match the rows".to_string()),
+        }
+        Ok(grid)
+    }
+
+    /// Reads "1,234 4/6*", or "4/6" without a puzzle number, and returns the score, `None`
+    /// for a lost game ("X/6").
+    fn parse_header(&mut self, header: &str) -> Result<Option<usize>, String> {
+        let invalid = || format!("unrecognized header {:?}", header);
+        let score = match header.trim().rsplit_once(' ') {
+            Some((number, score)) => {
+                let digits: String = number.chars().filter(char::is_ascii_digit).collect();
+                self.puzzle = Some(digits.parse().map_err(|_| invalid())?);
+                score
+            }
+            None => {
+                self.puzzle = None;
+                header.trim()
+            }
+        };
+
+        let score = score.strip_suffix('*').map_or(score, |score| {
+            self.hard_mode = true;
+            score
+        });
+        let (guesses, max_attempts) = score.split_once('/').ok_or_else(invalid)?;
+        self.max_attempts = max_attempts.parse().map_err(|_| invalid())?;
+        match guesses {
+            "X" => Ok(None),
+            guesses => guesses.parse().map(Some).map_err(|_| invalid()),
+        }
+    }
+
+    pub fn solved(&self) -> bool {
+        self.patterns
+            .last()
+            .is_some_and(|p| p.chars().all(|c| c == '2'))
+    }
+
+    /// The share text, with orange and blue tiles when `high_contrast` is set.
+    pub fn to_text(&self, high_contrast: bool) -> String {
+        let score = if self.solved() {
+            self.patterns.len().to_string()
+        } else {
+            "X".to_string()
+        };
+        let mut text = match self.puzzle {
+            Some(puzzle) => format!("Wordle {} ", group_thousands(puzzle)),
+            None => "Wordle ".to_string(),
+        };
+        text.push_str(&format!("{}/{}", score, self.max_attempts));
+        if self.hard_mode {
+            text.push('*');
+        }
+        text.push_str("\n\n");
+
+        let (green, yellow) = match high_contrast {
+            true => ('\u{1F7E7}', '\u{1F7E6}'),
+            false => ('\u{1F7E9}', '\u{1F7E8}'),
+        };
+        for pattern in &self.patterns {
+            text.extend(pattern.chars().map(|c| match c {
+                '2' => green,
+                '1' => yellow,
+                _ => '\u{2B1B}',
+            }));
+            text.push('\n');
+        }
+        text
+    }
+
+    /// For every row, the words from `guesses` that get its pattern against `answer`.
+    pub fn possible_guesses(&self, answer: &str, guesses: &[String]) -> Vec<Vec<String>> {
+        self.patterns
+            .iter()
+            .map(|pattern| {
+                guesses
+                    .iter()
+                    .filter(|guess| pattern_from_guess(guess, answer) == *pattern)
+                    .cloned()
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Answers that fit every row, with the number of guess sequences from `guesses` that
+    /// produce this grid against them, most first.
+    pub fn rank_answers(&self, answers: &[String], guesses: &[String]) -> Vec<(String, u128)> {
+        #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+        let candidates = answers.par_iter();
+        #[cfg(not(all(feature = "parallel", not(target_arch = "wasm32"))))]
+        let candidates = answers.iter();
+        let mut ranked: Vec<(String, u128)> = candidates
+            .filter_map(|answer| {
+                // pattern -> number of guesses that get it
+                let mut counts: AHashMap<String, u128> = AHashMap::new();
+                for guess in guesses {
+                    *counts.entry(pattern_from_guess(guess, answer)).or_default() += 1;
+                }
+                // long grids over big guess lists can outgrow even u128: saturate, but let a
+                // row no guess fits still give 0
+                let grids = self
+                    .patterns
+                    .iter()
+                    .map(|pattern| counts.get(pattern).copied().unwrap_or(0))
+                    .fold(1, u128::saturating_mul);
+                (grids > 0).then(|| (answer.clone(), grids))
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked
+    }
+}
+
+fn group_thousands(number: u32) -> String {
+    let digits = number.to_string();
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+#[test]
+fn test_share_grid_round_trip() {
+    let text = "Wordle 1,234 3/6*\n\n\u{2B1B}\u{1F7E8}\u{2B1C}\u{FE0F}\u{2B1B}\u{1F7E9}\n\
+                \u{1F7E7}\u{1F7E6}\u{2B1B}\u{2B1B}\u{1F7E7}\n\
+                \u{1F7E9}\u{1F7E9}\u{1F7E9}\u{1F7E9}\u{1F7E9}\n";
+    let grid = ShareGrid::parse(text).unwrap();
+    assert_eq!(grid.puzzle, Some(1234));
+    assert!(grid.hard_mode && grid.solved());
+    assert_eq!(grid.patterns, vec!["01002", "21002", "22222"]);
+    assert_eq!(ShareGrid::parse(&grid.to_text(true)).unwrap(), grid);
+    assert!(grid.to_text(false).starts_with("Wordle 1,234 3/6*\n\n"));
+
+    assert!(
+        ShareGrid::parse("Wordle 1,234 4/6\n\n\u{1F7E9}\u{1F7E9}\u{1F7E9}\u{1F7E9}\u{1F7E9}")
+            .is_err()
+    );
+    let (gray, green) = ("\u{2B1B}".repeat(5), "\u{1F7E9}".repeat(5));
+    let extra_row = format!("Wordle 2/6\n\n{}\n{}\n{}\n", gray, green, gray);
+    assert!(ShareGrid::parse(&extra_row).is_err());
+    let short_loss = format!("Wordle X/6\n\n{}\n{}\n", gray, gray);
+    assert!(ShareGrid::parse(&short_loss).is_err());
+    let loss = format!("Wordle X/3\n\n{}\n{}\n{}\n", gray, gray, gray);
+    assert!(!ShareGrid::parse(&loss).unwrap().solved());
+
+    let unnumbered = ShareGrid {
+        puzzle: None,
+        ..grid.clone()
+    };
+    assert!(unnumbered.to_text(false).starts_with("Wordle 3/6*\n\n"));
+    assert_eq!(
+        ShareGrid::parse(&unnumbered.to_text(false)).unwrap(),
+        unnumbered
+    );
+
+    let words = ["crane", "slate", "trace"].map(String::from);
+    assert_eq!(grid.possible_guesses("trace", &words)[2], vec!["trace"]);
+}
+
+#[test]
+fn test_rank_answers_counts_grids() {
+    let grid = ShareGrid::parse("\u{2B1B}\u{2B1B}\u{1F7E9}\u{1F7E9}\u{1F7E9}\n\u{1F7E9}\u{1F7E9}\u{1F7E9}\u{1F7E9}\u{1F7E9}").unwrap();
+    let words = ["crate", "slate", "plate", "spine"].map(String::from);
+    // only crate can be reached from both slate and plate, spine not at all
+    let ranked = grid.rank_answers(&words, &words);
+    let expected = [("crate", 2), ("plate", 1), ("slate", 1)];
+    assert_eq!(ranked, expected.map(|(w, n)| (w.to_string(), n)));
+
+    let long = ShareGrid {
+        patterns: vec!["22222".to_string(); 40],
+        ..grid.clone()
+    };
+    let many: Vec<String> = vec!["crate".to_string(); 10];
+    assert_eq!(
+        long.rank_answers(&words[..1], &many),
+        vec![("crate".to_string(), u128::MAX)]
+    );
+}
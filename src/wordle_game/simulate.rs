@@ -0,0 +1,140 @@
+#[cfg(feature = "tui")]
+use indicatif::{ProgressBar, ProgressStyle};
+use std::io;
+
+use super::WordleGame;
+
+/// One offline game of the solver against a known answer.
+#[derive(Debug, Clone)]
+pub struct GameResult {
+    pub answer: String,
+    pub guesses: Vec<String>,
+    pub patterns: Vec<String>,
+    pub solved: bool,
+    /// `(candidate entropy before the guess, guesses it took from there)` for every row.
+    pub samples: Vec<(f64, f64)>,
+}
+
+impl WordleGame {
+    /// Plays against `answer` from the current state until solved or out of attempts.
+    ///
+    /// `opening` skips the first search, which is the same for every answer.
+    pub fn play_against(
+        &mut self,
+        answer: &str,
+        opening: Option<&str>,
+    ) -> Result<GameResult, io::Error> {
+        let mut result = GameResult {
+            answer: answer.to_string(),
+            guesses: vec![],
+            patterns: vec![],
+            solved: false,
+            samples: vec![],
+        };
+        let mut entropies = vec![];
+
+        for row_index in 0..self.max_attempts {
+            entropies.push(self.candidate_entropy());
+            let guess_word = match opening {
+                Some(word) if row_index == 0 => word.to_string(),
+                _ => self.entrohpy_allgorithm()?.best().word.clone(),
+            };
+            let pattern = self.pattern_from_guess(&guess_word, answer);
+            self.add_feedback(&guess_word, &pattern);
+            result.guesses.push(guess_word);
+            result.patterns.push(pattern);
+
+            if result.guesses.last().map(String::as_str) == Some(answer) {
+                result.solved = true;
+                break;
+            }
+        }
+
+        let total = result.guesses.len();
+        result.samples = entropies
+            .into_iter()
+            .enumerate()
+            .map(|(row, h)| (h, (total - row) as f64))
+            .collect();
+        Ok(result)
+    }
+}
+
+/// Plays one offline game per answer with a fresh copy of `game`'s configuration.
+///
+/// `progress` draws a progress bar on stderr when built with the `tui` feature.
+pub fn simulate(
+    game: &mut WordleGame,
+    answers: &[String],
+    progress: bool,
+) -> Result<Vec<GameResult>, io::Error> {
+    game.reset();
+    let opening = game.entrohpy_allgorithm()?.best().word.clone();
+
+    let bar = progress_bar(answers.len(), progress);
+    let mut results = Vec::with_capacity(answers.len());
+    for answer in answers {
+        game.reset();
+        let result = game.play_against(answer, Some(&opening))?;
+        bar.tick(answer, result.guesses.len());
+        results.push(result);
+    }
+    bar.finish();
+
+    game.reset();
+    Ok(results)
+}
+
+#[cfg(feature = "tui")]
+struct Progress(ProgressBar);
+
+#[cfg(feature = "tui")]
+fn progress_bar(len: usize, visible: bool) -> Progress {
+    let bar = if visible {
+        ProgressBar::new(len as u64)
+    } else {
+        ProgressBar::hidden()
+    };
+    bar.set_style(
+        ProgressStyle::with_template("{bar:40} {pos}/{len} [{elapsed_precise}] {msg}")
+            .unwrap_or_else(|_| ProgressStyle::default_bar()),
+    );
+    Progress(bar)
+}
+
+#[cfg(feature = "tui")]
+impl Progress {
+    fn tick(&self, answer: &str, guesses: usize) {
+        self.0.set_message(format!("{} in {}", answer, guesses));
+        self.0.inc(1);
+    }
+
+    fn finish(&self) {
+        self.0.finish_and_clear();
+    }
+}
+
+#[cfg(not(feature = "tui"))]
+struct Progress;
+
+#[cfg(not(feature = "tui"))]
+fn progress_bar(_len: usize, _visible: bool) -> Progress {
+    Progress
+}
+
+#[cfg(not(feature = "tui"))]
+impl Progress {
+    fn tick(&self, _answer: &str, _guesses: usize) {}
+
+    fn finish(&self) {}
+}
+
+#[test]
+fn test_play_against_solves_small_list() {
+    let mut game = super::small_game();
+
+    let result = game.play_against("plate", None).unwrap();
+    assert!(result.solved);
+    assert_eq!(result.guesses.last().unwrap(), "plate");
+    assert_eq!(result.samples.len(), result.guesses.len());
+}
@@ -0,0 +1,125 @@
+use std::io;
+use std::path::Path;
+
+/// How `entrohpy_allgorithm` picks among the scored guesses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "kebab-case"))]
+pub enum Strategy {
+    /// Always play the guess with the most expected information.
+    #[default]
+    MaxEntropy,
+    /// Play the guess with the lowest expected total number of guesses, which favours
+    /// remaining candidates once they have a real chance of winning on the spot.
+    ExpectedScore,
+}
+
+impl Strategy {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "entropy" | "max-entropy" => Some(Strategy::MaxEntropy),
+            "expected-score" | "score" => Some(Strategy::ExpectedScore),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Strategy::MaxEntropy => "entropy",
+            Strategy::ExpectedScore => "expected-score",
+        }
+    }
+}
+
+/// Estimated number of guesses still needed (including the next one) when `h` bits of
+/// uncertainty remain: `1 + a * h + b * log2(1 + h)`.
+///
+/// The defaults are only a starting point; `game simulate --fit-curve` fits a curve to the
+/// answer list at hand.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreCurve {
+    pub a: f64,
+    pub b: f64,
+}
+
+impl Default for ScoreCurve {
+    fn default() -> Self {
+        ScoreCurve {
+            a: 0.0950,
+            b: 0.3991,
+        }
+    }
+}
+
+impl ScoreCurve {
+    pub fn expected_guesses(&self, h: f64) -> f64 {
+        let h = h.max(0.0);
+        1.0 + self.a * h + self.b * (1.0 + h).log2()
+    }
+
+    /// Expected total guesses for a word that is the answer with `probability` and whose
+    /// feedback carries `information` bits out of the `uncertainty` still left.
+    pub fn expected_score(&self, probability: f64, uncertainty: f64, information: f64) -> f64 {
+        if probability >= 1.0 {
+            return 1.0;
+        }
+        // the all-green bucket leaves no uncertainty, so spread what remains over the others
+        let remaining = (uncertainty - information) / (1.0 - probability);
+        probability + (1.0 - probability) * (1.0 + self.expected_guesses(remaining))
+    }
+
+    /// Least-squares fit over `(entropy, guesses needed)` samples from simulated games.
+    pub fn fit(samples: &[(f64, f64)]) -> Option<Self> {
+        // normal equations for y - 1 = a * x1 + b * x2
+        let (mut s11, mut s12, mut s22, mut s1y, mut s2y) = (0.0, 0.0, 0.0, 0.0, 0.0);
+        for &(h, guesses) in samples {
+            let (x1, x2, y) = (h, (1.0 + h).log2(), guesses - 1.0);
+            s11 += x1 * x1;
+            s12 += x1 * x2;
+            s22 += x2 * x2;
+            s1y += x1 * y;
+            s2y += x2 * y;
+        }
+        let det = s11 * s22 - s12 * s12;
+        if det.abs() < 1e-12 {
+            return None;
+        }
+        Some(ScoreCurve {
+            a: (s1y * s22 - s2y * s12) / det,
+            b: (s2y * s11 - s1y * s12) / det,
+        })
+    }
+
+    /// Reads a curve saved by [`ScoreCurve::save`]: the two coefficients on one line.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, io::Error> {
+        let contents = std::fs::read_to_string(path.as_ref())?;
+        let values: Vec<f64> = contents
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        match values.as_slice() {
+            [a, b] => Ok(ScoreCurve { a: *a, b: *b }),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: expected two coefficients", path.as_ref().display()),
+            )),
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), io::Error> {
+        std::fs::write(path, format!("{} {}\n", self.a, self.b))
+    }
+}
+
+#[test]
+fn test_fit_recovers_curve() {
+    let curve = ScoreCurve { a: 0.2, b: 0.5 };
+    let samples: Vec<(f64, f64)> = (0..40)
+        .map(|i| i as f64 * 0.3)
+        .map(|h| (h, curve.expected_guesses(h)))
+        .collect();
+    let fitted = ScoreCurve::fit(&samples).unwrap();
+    assert!((fitted.a - curve.a).abs() < 1e-9);
+    assert!((fitted.b - curve.b).abs() < 1e-9);
+}
@@ -0,0 +1,185 @@
+use ahash::AHashSet;
+use std::fmt;
+use std::io::{self, Read};
+use std::path::Path;
+
+use super::Alphabet;
+
+const ANSWERS: &str = include_str!("possible_anwsers.txt");
+const GUESSES: &str = include_str!("wordle_possibles.txt");
+
+/// A rejected line from a word-list source.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub source: String,
+    pub line: usize,
+    pub word: String,
+    pub reason: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: {:?} {}",
+            self.source, self.line, self.word, self.reason
+        )
+    }
+}
+
+/// A validated, de-duplicated list of words merged from one or more sources.
+///
+/// Blank lines and lines starting with `#` are skipped. Every other line is normalized
+/// through the alphabet and rejected with a [`Diagnostic`] when it has the wrong length or
+/// uses letters outside the alphabet.
+#[derive(Clone)]
+pub struct WordList {
+    pub alphabet: Alphabet,
+    pub word_length: usize,
+    pub words: Vec<String>,
+    pub diagnostics: Vec<Diagnostic>,
+    pub lines_read: usize,
+    pub duplicates: usize,
+    pub excluded: usize,
+    seen: AHashSet<String>,
+    exclusions: AHashSet<String>,
+}
+
+impl WordList {
+    pub fn new(word_length: usize, alphabet: Alphabet) -> Self {
+        WordList {
+            alphabet,
+            word_length,
+            words: vec![],
+            diagnostics: vec![],
+            lines_read: 0,
+            duplicates: 0,
+            excluded: 0,
+            seen: AHashSet::new(),
+            exclusions: AHashSet::new(),
+        }
+    }
+
+    /// The answer list shipped with the solver.
+    pub fn builtin_answers() -> Self {
+        let mut list = WordList::new(5, Alphabet::english());
+        list.include_str("possible_anwsers.txt", ANSWERS);
+        list
+    }
+
+    /// Every word Wordle accepts as a guess: the answers followed by the other allowed words.
+    pub fn builtin_guesses() -> Self {
+        let mut list = WordList::builtin_answers();
+        list.include_str("wordle_possibles.txt", GUESSES);
+        list
+    }
+
+    /// Adds the words from a file, or from stdin when `path` is `-`.
+    pub fn include_path(&mut self, path: impl AsRef<Path>) -> Result<(), io::Error> {
+        let (source, contents) = read_source(path.as_ref())?;
+        self.include_str(&source, &contents);
+        Ok(())
+    }
+
+    pub fn include_str(&mut self, source: &str, contents: &str) {
+        for word in self.parse(source, contents) {
+            if self.exclusions.contains(&word) {
+                self.excluded += 1;
+            } else if self.seen.insert(word.clone()) {
+                self.words.push(word);
+            } else {
+                self.duplicates += 1;
+            }
+        }
+    }
+
+    /// Removes the words from a file (or stdin) and keeps them out of later includes.
+    pub fn exclude_path(&mut self, path: impl AsRef<Path>) -> Result<(), io::Error> {
+        let (source, contents) = read_source(path.as_ref())?;
+        self.exclude_str(&source, &contents);
+        Ok(())
+    }
+
+    pub fn exclude_str(&mut self, source: &str, contents: &str) {
+        for word in self.parse(source, contents) {
+            if self.seen.remove(&word) {
+                self.words.retain(|w| *w != word);
+                self.excluded += 1;
+            }
+            self.exclusions.insert(word);
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "{} words ({} letters, {} alphabet): {} lines read, {} rejected, {} duplicates, {} excluded",
+            self.words.len(),
+            self.word_length,
+            self.alphabet.name,
+            self.lines_read,
+            self.diagnostics.len(),
+            self.duplicates,
+            self.excluded
+        )
+    }
+
+    fn parse(&mut self, source: &str, contents: &str) -> Vec<String> {
+        let mut accepted = vec![];
+        for (index, raw) in contents.lines().enumerate() {
+            let trimmed = raw.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            self.lines_read += 1;
+            let reject = |reason: String| Diagnostic {
+                source: source.to_string(),
+                line: index + 1,
+                word: trimmed.to_string(),
+                reason,
+            };
+            match self.alphabet.normalize(trimmed) {
+                None => self.diagnostics.push(reject(format!(
+                    "uses letters outside the {} alphabet",
+                    self.alphabet.name
+                ))),
+                Some(word) if word.chars().count() != self.word_length => {
+                    self.diagnostics.push(reject(format!(
+                        "has {} letters, expected {}",
+                        word.chars().count(),
+                        self.word_length
+                    )))
+                }
+                Some(word) => accepted.push(word),
+            }
+        }
+        accepted
+    }
+}
+
+fn read_source(path: &Path) -> Result<(String, String), io::Error> {
+    if path == Path::new("-") {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        Ok(("<stdin>".to_string(), contents))
+    } else {
+        let contents = std::fs::read_to_string(path)?;
+        Ok((path.display().to_string(), contents))
+    }
+}
+
+#[test]
+fn test_word_list_validates_and_merges() {
+    let mut list = WordList::new(5, Alphabet::english());
+    list.include_str(
+        "a.txt",
+        "Crane\n\n# comment\ncrane\nslate\ntoolong\nnaïve\n",
+    );
+    list.exclude_str("ex.txt", "slate\n");
+    list.include_str("b.txt", "slate\nirate\n");
+
+    assert_eq!(list.words, vec!["crane", "irate"]);
+    assert_eq!(list.duplicates, 1);
+    assert_eq!(list.excluded, 2);
+    let lines: Vec<usize> = list.diagnostics.iter().map(|d| d.line).collect();
+    assert_eq!(lines, vec![6, 7]);
+}